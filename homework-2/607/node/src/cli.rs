@@ -48,4 +48,7 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Hash a file and check whether it is claimed in the local chain database.
+    Poe(crate::poe::PoeCmd),
 }
@@ -0,0 +1,111 @@
+//! The `poe` subcommand: hash a file and look its claim up in the local database.
+
+use std::{fs, path::PathBuf, sync::Arc};
+
+use codec::Encode;
+use pallet_poe::HashAlgorithm;
+use pallet_poe_rpc::PoeRuntimeApi;
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use solochain_template_runtime::{opaque::Block, AccountId, BlockNumber, RuntimeCall};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::bytes::to_hex;
+use sp_runtime::{generic::BlockId, BoundedVec};
+
+/// Hash algorithms a file can be hashed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Algorithm {
+    /// BLAKE2b with a 256-bit output, as used by the runtime.
+    Blake2_256,
+    /// Keccak-256, as used by Ethereum.
+    Keccak256,
+    /// SHA-256.
+    Sha2_256,
+}
+
+impl From<Algorithm> for HashAlgorithm {
+    fn from(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Blake2_256 => HashAlgorithm::Blake2_256,
+            Algorithm::Keccak256 => HashAlgorithm::Keccak256,
+            Algorithm::Sha2_256 => HashAlgorithm::Sha2_256,
+        }
+    }
+}
+
+/// Hash a file, print the `create_claim` call for it and check whether it is already claimed.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct PoeCmd {
+    /// The file to hash.
+    pub file: PathBuf,
+
+    /// Algorithm to hash the file with.
+    #[arg(long, value_enum, default_value_t = Algorithm::Blake2_256)]
+    pub algorithm: Algorithm,
+
+    /// Block to look the claim up at, by number or hash. Defaults to the best block.
+    #[arg(long)]
+    pub at: Option<BlockNumberOrHash>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+}
+
+impl PoeCmd {
+    /// Run the command against the chain database behind `client`.
+    pub fn run<C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+    where
+        C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+        C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber>,
+    {
+        let data = fs::read(&self.file)?;
+        let digest = HashAlgorithm::from(self.algorithm).hash(&data);
+        let claim = BoundedVec::try_from(digest.to_vec())
+            .map_err(|_| "The digest is longer than the runtime's `MaxClaimLength`.")?;
+        let call = RuntimeCall::PoeModule(pallet_poe::Call::create_claim { claim });
+
+        println!("digest:    {}", to_hex(&digest, false));
+        println!("call data: {}", to_hex(&call.encode(), false));
+
+        let hash = match self.at.as_ref().map(|at| at.parse::<Block>()).transpose()? {
+            None => client.info().best_hash,
+            Some(BlockId::Hash(hash)) => hash,
+            Some(BlockId::Number(number)) => client
+                .hash(number)?
+                .ok_or_else(|| format!("Block #{} is not in the database.", number))?,
+        };
+
+        match client.runtime_api().get_claim(hash, digest.to_vec()).map_err(|e| e.to_string())? {
+            Some(details) => println!(
+                "claimed by {} in block #{} ({} ms), as of block {:?}",
+                details.owner, details.block_number, details.timestamp, hash
+            ),
+            None => println!("not claimed as of block {:?}", hash),
+        }
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for PoeCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+}
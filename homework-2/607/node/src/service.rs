@@ -209,7 +209,7 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
                 pool: pool.clone(),
                 deny_unsafe,
             };
-            crate::rpc::create_full(deps).map_err(Into::into)
+            crate::rpc::create_full::<_, _, FullBackend>(deps).map_err(Into::into)
         })
     };
 
@@ -0,0 +1,252 @@
+//! RPC interface for the proof-of-existence pallet.
+
+#![warn(missing_docs)]
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Decode};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use sc_client_api::{Backend, ProofProvider, StorageProvider};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, storage::StorageKey, Bytes};
+use sp_runtime::traits::{Block as BlockT, NumberFor, Zero};
+
+pub use pallet_poe_runtime_api::PoeApi as PoeRuntimeApi;
+pub use poe_verify::ClaimProof;
+
+/// A claim as returned by `poe_getClaim`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimInfo<AccountId, BlockNumber, Moment> {
+    /// Current owner of the claim.
+    pub owner: AccountId,
+    /// Block in which the claim was created or last changed hands.
+    pub block_number: BlockNumber,
+    /// Timestamp of `block_number`.
+    ///
+    /// Claims made before timestamps were recorded on-chain fall back to the state of that
+    /// block, and get `None` if it is no longer available on this node.
+    pub timestamp: Option<Moment>,
+}
+
+/// PoE RPC methods.
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber, Moment> {
+    /// Return the claim stored under the raw bytes `claim`, if any.
+    #[method(name = "poe_getClaim")]
+    fn get_claim(
+        &self,
+        claim: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ClaimInfo<AccountId, BlockNumber, Moment>>>;
+
+    /// Return up to `limit` claims of `owner`, skipping the first `offset`.
+    #[method(name = "poe_claimsOf")]
+    fn claims_of(
+        &self,
+        owner: AccountId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Bytes>>;
+
+    /// Return the claim stored under `claim` with a proof of its storage entry, checkable
+    /// offline with `poe-verify`.
+    ///
+    /// `at` must be a finalized block, and defaults to the last finalized one. Claims that
+    /// have expired by `at` are refused, as `poe_getClaim` does not return them either.
+    #[method(name = "poe_getClaimProof")]
+    fn get_claim_proof(
+        &self,
+        claim: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ClaimProof<BlockHash>>;
+}
+
+/// Error codes of the PoE RPC.
+pub enum Error {
+    /// The call to the runtime failed.
+    RuntimeError,
+    /// Reading the node's storage failed.
+    StorageError,
+    /// The block is not finalized.
+    NotFinalized,
+    /// The claim has expired, but has not been swept yet.
+    ClaimExpired,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::StorageError => 2,
+            Error::NotFinalized => 3,
+            Error::ClaimExpired => 4,
+        }
+    }
+}
+
+/// Provides the PoE RPC methods on top of a client.
+pub struct Poe<C, Block, BE> {
+    client: Arc<C>,
+    _marker: PhantomData<(Block, BE)>,
+}
+
+impl<C, Block, BE> Poe<C, Block, BE> {
+    /// Create a new instance of the PoE RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, BE> Poe<C, Block, BE>
+where
+    Block: BlockT,
+    BE: Backend<Block>,
+    C: HeaderBackend<Block> + StorageProvider<Block, BE>,
+{
+    /// `pallet_timestamp::Now` as of block `number`.
+    fn timestamp_at<Moment: Decode>(&self, number: NumberFor<Block>) -> RpcResult<Option<Moment>> {
+        let Some(hash) = self.client.hash(number).map_err(storage_error)? else {
+            return Ok(None);
+        };
+        // `pallet_timestamp` is named `Timestamp` in `construct_runtime!`.
+        let key = StorageKey([twox_128(b"Timestamp"), twox_128(b"Now")].concat());
+
+        // Pruned state shows up as an error here, which only means the timestamp is unknown.
+        let Ok(Some(data)) = self.client.storage(hash, &key) else {
+            return Ok(None);
+        };
+
+        Moment::decode(&mut &data.0[..])
+            .map(Some)
+            .map_err(storage_error)
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query PoE runtime API.",
+        Some(format!("{:?}", e)),
+    )
+}
+
+fn storage_error(e: impl std::fmt::Debug) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        Error::StorageError.into(),
+        "Unable to read node storage.",
+        Some(format!("{:?}", e)),
+    )
+}
+
+impl<C, Block, BE, AccountId, Moment>
+    PoeApiServer<<Block as BlockT>::Hash, AccountId, NumberFor<Block>, Moment> for Poe<C, Block, BE>
+where
+    Block: BlockT,
+    BE: Backend<Block> + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + StorageProvider<Block, BE>,
+    C: ProofProvider<Block> + Send + Sync + 'static,
+    C::Api: PoeRuntimeApi<Block, AccountId, NumberFor<Block>, Moment>,
+    AccountId: Codec + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    Moment: Codec + Zero + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn get_claim(
+        &self,
+        claim: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ClaimInfo<AccountId, NumberFor<Block>, Moment>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let Some(details) = api.get_claim(at_hash, claim.to_vec()).map_err(runtime_error)? else {
+            return Ok(None);
+        };
+
+        let timestamp = if details.timestamp.is_zero() {
+            self.timestamp_at(details.block_number)?
+        } else {
+            Some(details.timestamp)
+        };
+
+        Ok(Some(ClaimInfo {
+            timestamp,
+            owner: details.owner,
+            block_number: details.block_number,
+        }))
+    }
+
+    fn claims_of(
+        &self,
+        owner: AccountId,
+        offset: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Bytes>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let claims = api
+            .claims_of(at_hash, owner, offset, limit)
+            .map_err(runtime_error)?;
+
+        Ok(claims.into_iter().map(Bytes::from).collect())
+    }
+
+    fn get_claim_proof(
+        &self,
+        claim: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ClaimProof<<Block as BlockT>::Hash>> {
+        let info = self.client.info();
+        let at_hash = at.unwrap_or(info.finalized_hash);
+
+        // A proof against a block that may still be reverted proves nothing.
+        let number = self.client.number(at_hash).map_err(storage_error)?;
+        let finalized = number.is_some_and(|number| {
+            number <= info.finalized_number
+                && self.client.hash(number).ok().flatten() == Some(at_hash)
+        });
+        if !finalized {
+            return Err(ErrorObject::owned(
+                Error::NotFinalized.into(),
+                "Block is not finalized.",
+                Some(format!("{:?}", at_hash)),
+            ));
+        }
+
+        // `pallet_poe` is named `PoeModule` in `construct_runtime!`.
+        let key = poe_verify::proofs_key("PoeModule", &claim);
+        let value = self
+            .client
+            .storage(at_hash, &StorageKey(key.clone()))
+            .map_err(storage_error)?;
+        // An expired claim stays in storage until it is swept, but is no longer a claim.
+        if value.is_some() {
+            let api = self.client.runtime_api();
+            if api.get_claim(at_hash, claim.to_vec()).map_err(runtime_error)?.is_none() {
+                return Err(ErrorObject::owned(
+                    Error::ClaimExpired.into(),
+                    "Claim has expired.",
+                    Some(format!("{:?}", claim)),
+                ));
+            }
+        }
+        let proof = self
+            .client
+            .read_proof(at_hash, &mut std::iter::once(&key[..]))
+            .map_err(storage_error)?;
+
+        Ok(ClaimProof {
+            at: at_hash,
+            key: Bytes(key),
+            value: value.map(|data| Bytes(data.0)),
+            proof: proof.into_iter_nodes().map(Bytes).collect(),
+        })
+    }
+}
@@ -0,0 +1,77 @@
+//! Runtime API definition for the proof-of-existence pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+/// The on-chain data of a single claim.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ClaimDetails<AccountId, BlockNumber, Moment> {
+    pub owner: AccountId,
+    /// Block in which the claim was created or last changed hands.
+    pub block_number: BlockNumber,
+    /// Time of `block_number`, zero if it was not recorded.
+    pub timestamp: Moment,
+}
+
+/// A past owner of a claim.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ProvenanceRecord<AccountId, BlockNumber, Moment> {
+    pub owner: AccountId,
+    /// Block in which `owner` handed the claim on.
+    pub block_number: BlockNumber,
+    /// Time of `block_number`.
+    pub timestamp: Moment,
+}
+
+/// Whether a claim can be relied on.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ClaimStatus<AccountId, BlockNumber> {
+    Active,
+    /// The claim was revoked by `revoker` in `block_number`, for `reason`.
+    Revoked {
+        revoker: AccountId,
+        reason: u16,
+        block_number: BlockNumber,
+    },
+    /// There is no such claim, or it was revoked without leaving a tombstone.
+    Unknown,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait PoeApi<AccountId, BlockNumber, Moment>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Moment: Codec,
+    {
+        /// The claim stored under `claim`, if any.
+        fn get_claim(claim: Vec<u8>) -> Option<ClaimDetails<AccountId, BlockNumber, Moment>>;
+
+        /// The claim stored under `claim` within `namespace`, if any.
+        fn get_namespaced_claim(
+            namespace: Vec<u8>,
+            claim: Vec<u8>,
+        ) -> Option<ClaimDetails<AccountId, BlockNumber, Moment>>;
+
+        /// Whether `claim` is active, revoked with a reason, or unknown.
+        fn claim_status(claim: Vec<u8>) -> ClaimStatus<AccountId, BlockNumber>;
+
+        /// Number of claims owned by `owner`.
+        fn claim_count(owner: AccountId) -> u32;
+
+        /// Claims owned by `owner`, skipping the first `offset` and returning at most `limit`.
+        fn claims_of(owner: AccountId, offset: u32, limit: u32) -> Vec<Vec<u8>>;
+
+        /// Past owners of the claim stored under `claim`, oldest first.
+        fn provenance(claim: Vec<u8>) -> Vec<ProvenanceRecord<AccountId, BlockNumber, Moment>>;
+
+        /// Whether `leaf` is leaf number `index` of the anchored Merkle root `root`.
+        ///
+        /// `proof` holds the sibling hashes on the path from `leaf` up to `root`.
+        fn verify_inclusion(root: H256, leaf: H256, index: u32, proof: Vec<H256>) -> bool;
+    }
+}
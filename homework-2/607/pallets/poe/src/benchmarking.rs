@@ -3,9 +3,36 @@
 use super::*;
 
 use frame_benchmarking::v2::*;
-use frame_support::{pallet_prelude::Get, BoundedVec};
+use codec::Encode;
+use frame_support::{
+    pallet_prelude::Get,
+    traits::fungible::Mutate,
+    BoundedVec,
+};
 use frame_system::RawOrigin;
-use sp_std::vec;
+use sp_core::H256;
+use sp_runtime::traits::{Bounded, Hash, IdentifyAccount, Zero};
+use sp_std::{vec, vec::Vec};
+
+fn funded<T: Config>(who: &T::AccountId) {
+    let _ = T::Currency::set_balance(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn namespace<T: Config>() -> BoundedVec<u8, T::MaxNamespaceLength> {
+    BoundedVec::try_from(vec![0; T::MaxNamespaceLength::get() as usize]).unwrap()
+}
+
+/// `n` distinct claims of the maximum length.
+fn batch<T: Config>(n: u32) -> BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize> {
+    let claims: Vec<_> = (0..n)
+        .map(|i| {
+            let mut claim = vec![0; T::MaxClaimLength::get() as usize];
+            claim[..4].copy_from_slice(&i.to_le_bytes());
+            BoundedVec::try_from(claim).unwrap()
+        })
+        .collect();
+    BoundedVec::try_from(claims).unwrap()
+}
 
 #[benchmarks]
 mod benches {
@@ -14,14 +41,15 @@ mod benches {
     #[benchmark]
     fn create_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
         let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
 
         #[extrinsic_call]
         create_claim(RawOrigin::Signed(caller.clone()), claim.clone());
 
         assert_eq!(
-            Proofs::<T>::get(&claim),
-            Some((caller, frame_system::Pallet::<T>::block_number()))
+            Proofs::<T>::get(&claim).map(|info| info.owner),
+            Some(caller)
         );
 
         Ok(())
@@ -30,13 +58,14 @@ mod benches {
     #[benchmark]
     fn revoke_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
         let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
 
         let _ = Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
 
         assert_eq!(
-            Proofs::<T>::get(&claim),
-            Some((caller.clone(), frame_system::Pallet::<T>::block_number()))
+            Proofs::<T>::get(&claim).map(|info| info.owner),
+            Some(caller.clone())
         );
 
         #[extrinsic_call]
@@ -48,32 +77,672 @@ mod benches {
     }
 
     #[benchmark]
-    fn transfer_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+    fn offer_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let caller: T::AccountId = whitelisted_caller();
+        let target: T::AccountId = account("target", 0, 0);
+        funded::<T>(&caller);
+
+        let _ = Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+
+        #[extrinsic_call]
+        offer_claim(
+            RawOrigin::Signed(caller.clone()),
+            claim.clone(),
+            target.clone(),
+        );
+
+        assert_eq!(PendingOffers::<T>::get(&claim), Some(target));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn accept_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let caller: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("owner", 0, 0);
+        funded::<T>(&owner);
+        // The new owner holds a deposit of their own.
+        funded::<T>(&caller);
+
+        let _ = Pallet::<T>::create_claim(RawOrigin::Signed(owner.clone()).into(), claim.clone())?;
+        Pallet::<T>::offer_claim(
+            RawOrigin::Signed(owner.clone()).into(),
+            claim.clone(),
+            caller.clone(),
+        )?;
+
+        #[extrinsic_call]
+        accept_claim(RawOrigin::Signed(caller.clone()), claim.clone());
+
+        assert_eq!(
+            Proofs::<T>::get(&claim).map(|info| info.owner),
+            Some(caller.clone())
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_offer(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
         let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
         let caller: T::AccountId = whitelisted_caller();
         let target: T::AccountId = account("target", 0, 0);
+        funded::<T>(&caller);
 
         let _ = Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+        Pallet::<T>::offer_claim(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            target,
+        )?;
+
+        #[extrinsic_call]
+        cancel_offer(RawOrigin::Signed(caller.clone()), claim.clone());
+
+        assert!(PendingOffers::<T>::get(&claim).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_claim_with_expiry(
+        b: Linear<1, { T::MaxClaimLength::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        create_claim_with_expiry(RawOrigin::Signed(caller.clone()), claim.clone(), expires_at);
 
         assert_eq!(
-            Proofs::<T>::get(&claim),
-            Some((caller.clone(), frame_system::Pallet::<T>::block_number()))
+            Proofs::<T>::get(&claim).and_then(|info| info.expires_at),
+            Some(expires_at)
         );
 
+        Ok(())
+    }
+
+    #[benchmark]
+    fn renew_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim = BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let now = frame_system::Pallet::<T>::block_number();
+
+        Pallet::<T>::create_claim_with_expiry(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            now + 10u32.into(),
+        )?;
+
         #[extrinsic_call]
-        transfer_claim(
+        renew_claim(
             RawOrigin::Signed(caller.clone()),
             claim.clone(),
-            target.clone(),
+            Some(now + 20u32.into()),
+        );
+
+        assert_eq!(
+            Proofs::<T>::get(&claim).and_then(|info| info.expires_at),
+            Some(now + 20u32.into())
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn expire_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        Pallet::<T>::create_claim_with_expiry(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            expires_at,
+        )?;
+
+        #[block]
+        {
+            Pallet::<T>::expire_claim(expires_at, claim.clone());
+        }
+
+        assert!(Proofs::<T>::get(&claim).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn verify_inclusion(d: Linear<0, { T::MaxProofDepth::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let algorithm = HashAlgorithm::Keccak256;
+        let leaf = H256::repeat_byte(1);
+        let proof: BoundedVec<H256, T::MaxProofDepth> =
+            BoundedVec::try_from(vec![H256::repeat_byte(2); d as usize]).unwrap();
+        // The last leaf, so every level hashes the sibling first.
+        let index = 1u32.checked_shl(d).map_or(u32::MAX, |width| width - 1);
+        let root = algorithm.merkle_root(leaf, index, &proof);
+
+        Pallet::<T>::anchor_merkle_root(RawOrigin::Signed(caller.clone()).into(), root, algorithm)?;
+
+        #[extrinsic_call]
+        verify_inclusion(RawOrigin::Signed(caller), root, leaf, index, proof);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_digest_claim(
+        m: Linear<0, { T::MaxMimeTypeLength::get() }>,
+        u: Linear<0, { T::MaxUriLength::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let digest = H256::repeat_byte(1);
+        let mime_type = BoundedVec::try_from(vec![b'a'; m as usize]).unwrap();
+        let uri = BoundedVec::try_from(vec![b'a'; u as usize]).unwrap();
+
+        #[extrinsic_call]
+        create_digest_claim(
+            RawOrigin::Signed(caller.clone()),
+            digest,
+            HashAlgorithm::Sha2_256,
+            Some(mime_type),
+            Some(uri),
+        );
+
+        assert!(Metadata::<T>::contains_key(
+            BoundedVec::<u8, T::MaxClaimLength>::try_from(digest.as_bytes().to_vec()).unwrap()
+        ));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_claim_for(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let relayer: T::AccountId = whitelisted_caller();
+        funded::<T>(&relayer);
+        let signer = T::BenchmarkHelper::signer();
+        let owner = signer.clone().into_account();
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let deadline = frame_system::Pallet::<T>::block_number();
+        let payload = ClaimForPayload {
+            tag: CLAIM_FOR_TAG,
+            genesis_hash: frame_system::Pallet::<T>::block_hash(Zero::zero()),
+            owner: owner.clone(),
+            claim: claim.clone(),
+            nonce: 0,
+            deadline,
+        };
+        let signature = T::BenchmarkHelper::sign(&signer, &payload.encode());
+
+        #[extrinsic_call]
+        create_claim_for(
+            RawOrigin::Signed(relayer),
+            owner.clone(),
+            claim.clone(),
+            signature,
+            deadline,
+        );
+
+        assert_eq!(
+            Proofs::<T>::get(&claim).map(|info| info.owner),
+            Some(owner)
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn commit_claim() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let salted_hash = T::Hashing::hash(b"salted claim");
+
+        #[extrinsic_call]
+        commit_claim(RawOrigin::Signed(caller), salted_hash);
+
+        assert!(Commitments::<T>::contains_key(salted_hash));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reveal_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let salt = H256::repeat_byte(1);
+        let salted_hash = T::Hashing::hash_of(&(&claim, &salt));
+
+        Pallet::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), salted_hash)?;
+        // Someone else recorded the claim in the meantime, and has to be removed.
+        let other: T::AccountId = account("other", 0, 0);
+        funded::<T>(&other);
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + 1u32.into(),
+        );
+        Pallet::<T>::create_claim(RawOrigin::Signed(other).into(), claim.clone())?;
+
+        #[extrinsic_call]
+        reveal_claim(RawOrigin::Signed(caller.clone()), claim.clone(), salt);
+
+        assert_eq!(
+            Proofs::<T>::get(&claim).map(|info| info.owner),
+            Some(caller)
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn expire_commitment() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let salted_hash = T::Hashing::hash(b"salted claim");
+        let expires_at = frame_system::Pallet::<T>::block_number() + T::RevealWindow::get();
+
+        Pallet::<T>::commit_claim(RawOrigin::Signed(caller).into(), salted_hash)?;
+
+        #[block]
+        {
+            Pallet::<T>::expire_commitment(expires_at, salted_hash);
+        }
+
+        assert!(!Commitments::<T>::contains_key(salted_hash));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_co_owners(n: Linear<1, { T::MaxCoOwners::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+        let mut owners = vec![caller.clone()];
+        owners.extend((1..n).map(|i| account::<T::AccountId>("owner", i, 0)));
+        let owners: BoundedVec<_, T::MaxCoOwners> = BoundedVec::try_from(owners).unwrap();
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+
+        #[extrinsic_call]
+        set_co_owners(RawOrigin::Signed(caller), claim.clone(), owners, n);
+
+        assert!(CoOwners::<T>::contains_key(&claim));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_action(n: Linear<1, { T::MaxCoOwners::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+        let mut owners: Vec<T::AccountId> =
+            (1..n).map(|i| account::<T::AccountId>("owner", i, 0)).collect();
+        owners.push(caller.clone());
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+        Pallet::<T>::set_co_owners(
+            RawOrigin::Signed(caller.clone()).into(),
+            claim.clone(),
+            BoundedVec::try_from(owners.clone()).unwrap(),
+            n,
+        )?;
+        // Everyone but the caller approved already, so the caller's approval revokes the claim.
+        for owner in &owners[..owners.len() - 1] {
+            Pallet::<T>::approve_action(
+                RawOrigin::Signed(owner.clone()).into(),
+                claim.clone(),
+                ClaimAction::Revoke,
+            )?;
+        }
+
+        #[extrinsic_call]
+        approve_action(RawOrigin::Signed(caller), claim.clone(), ClaimAction::Revoke);
+
+        assert!(Proofs::<T>::get(&claim).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_claim_with_reason(
+        b: Linear<1, { T::MaxClaimLength::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+
+        #[extrinsic_call]
+        revoke_claim_with_reason(RawOrigin::Signed(caller), claim.clone(), 1);
+
+        assert!(Revocations::<T>::contains_key(&claim));
+
+        Ok(())
+    }
+    #[benchmark]
+    fn challenge_claim(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&owner);
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(owner).into(), claim.clone())?;
+
+        #[extrinsic_call]
+        challenge_claim(RawOrigin::Signed(caller.clone()), claim.clone());
+
+        assert_eq!(
+            Disputes::<T>::get(&claim).map(|dispute| dispute.challenger),
+            Some(caller)
         );
 
+        Ok(())
+    }
+
+    #[benchmark]
+    fn resolve_dispute(b: Linear<1, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let challenger: T::AccountId = account("challenger", 0, 0);
+        funded::<T>(&owner);
+        funded::<T>(&challenger);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+        let origin =
+            T::ArbitratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(owner).into(), claim.clone())?;
+        Pallet::<T>::challenge_claim(RawOrigin::Signed(challenger).into(), claim.clone())?;
+
+        // Upholding the challenge is the heavier verdict.
+        #[extrinsic_call]
+        resolve_dispute(origin as T::RuntimeOrigin, claim.clone(), Verdict::Upheld);
+
+        assert!(Proofs::<T>::get(&claim).is_none());
+        assert!(Disputes::<T>::get(&claim).is_none());
+        assert!(Revocations::<T>::contains_key(&claim));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_namespace() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let namespace = namespace::<T>();
+
+        #[extrinsic_call]
+        register_namespace(RawOrigin::Signed(caller.clone()), namespace.clone());
+
         assert_eq!(
-            Proofs::<T>::get(&claim),
-            Some((target.clone(), frame_system::Pallet::<T>::block_number()))
+            Namespaces::<T>::get(&namespace).map(|info| info.owner),
+            Some(caller)
         );
 
         Ok(())
     }
+
+    #[benchmark]
+    fn unregister_namespace() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let namespace = namespace::<T>();
+
+        Pallet::<T>::register_namespace(
+            RawOrigin::Signed(caller.clone()).into(),
+            namespace.clone(),
+        )?;
+
+        #[extrinsic_call]
+        unregister_namespace(RawOrigin::Signed(caller), namespace.clone());
+
+        assert!(Namespaces::<T>::get(&namespace).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_writer(
+        n: Linear<0, { T::MaxNamespaceWriters::get() - 1 }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let namespace = namespace::<T>();
+
+        Pallet::<T>::register_namespace(
+            RawOrigin::Signed(caller.clone()).into(),
+            namespace.clone(),
+        )?;
+        for i in 0..n {
+            Pallet::<T>::add_writer(
+                RawOrigin::Signed(caller.clone()).into(),
+                namespace.clone(),
+                account("writer", i, 0),
+            )?;
+        }
+        let writer: T::AccountId = account("writer", n, 0);
+
+        #[extrinsic_call]
+        add_writer(RawOrigin::Signed(caller), namespace.clone(), writer.clone());
+
+        assert!(Namespaces::<T>::get(&namespace)
+            .is_some_and(|info| info.writers.contains(&writer)));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_writer(
+        n: Linear<1, { T::MaxNamespaceWriters::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let namespace = namespace::<T>();
+
+        Pallet::<T>::register_namespace(
+            RawOrigin::Signed(caller.clone()).into(),
+            namespace.clone(),
+        )?;
+        for i in 0..n {
+            Pallet::<T>::add_writer(
+                RawOrigin::Signed(caller.clone()).into(),
+                namespace.clone(),
+                account("writer", i, 0),
+            )?;
+        }
+        // The last writer is the furthest to search for.
+        let writer: T::AccountId = account("writer", n - 1, 0);
+
+        #[extrinsic_call]
+        remove_writer(RawOrigin::Signed(caller), namespace.clone(), writer.clone());
+
+        assert!(Namespaces::<T>::get(&namespace)
+            .is_some_and(|info| !info.writers.contains(&writer)));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_namespaced_claim(
+        b: Linear<1, { T::MaxClaimLength::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let namespace = namespace::<T>();
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+
+        Pallet::<T>::register_namespace(
+            RawOrigin::Signed(caller.clone()).into(),
+            namespace.clone(),
+        )?;
+
+        #[extrinsic_call]
+        create_namespaced_claim(RawOrigin::Signed(caller), namespace.clone(), claim.clone());
+
+        assert!(NamespacedProofs::<T>::contains_key(&namespace, &claim));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_namespaced_claim(
+        b: Linear<1, { T::MaxClaimLength::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let namespace = namespace::<T>();
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; b as usize]).unwrap();
+
+        Pallet::<T>::register_namespace(
+            RawOrigin::Signed(caller.clone()).into(),
+            namespace.clone(),
+        )?;
+        Pallet::<T>::create_namespaced_claim(
+            RawOrigin::Signed(caller.clone()).into(),
+            namespace.clone(),
+            claim.clone(),
+        )?;
+
+        #[extrinsic_call]
+        revoke_namespaced_claim(RawOrigin::Signed(caller), namespace.clone(), claim.clone());
+
+        assert!(!NamespacedProofs::<T>::contains_key(&namespace, &claim));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_delegate(d: Linear<0, { T::MaxDelegates::get() - 1 }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+        for i in 0..d {
+            Pallet::<T>::add_delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                claim.clone(),
+                account("delegate", i, 0),
+                DelegatePermissions::ALL,
+                None,
+            )?;
+        }
+        let delegate: T::AccountId = account("delegate", d, 0);
+
+        #[extrinsic_call]
+        add_delegate(
+            RawOrigin::Signed(caller),
+            claim.clone(),
+            delegate.clone(),
+            DelegatePermissions::ALL,
+            None,
+        );
+
+        assert!(Delegates::<T>::get(&claim).iter().any(|entry| entry.who == delegate));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_delegate(d: Linear<1, { T::MaxDelegates::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claim: BoundedVec<u8, T::MaxClaimLength> =
+            BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+
+        Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())?;
+        for i in 0..d {
+            Pallet::<T>::add_delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                claim.clone(),
+                account("delegate", i, 0),
+                DelegatePermissions::ALL,
+                None,
+            )?;
+        }
+        // The last delegate is the furthest to search for.
+        let delegate: T::AccountId = account("delegate", d - 1, 0);
+
+        #[extrinsic_call]
+        remove_delegate(RawOrigin::Signed(caller), claim.clone(), delegate.clone());
+
+        assert!(!Delegates::<T>::get(&claim).iter().any(|entry| entry.who == delegate));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_claims(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claims = batch::<T>(n);
+
+        #[extrinsic_call]
+        create_claims(RawOrigin::Signed(caller.clone()), claims.clone());
+
+        assert!(claims
+            .iter()
+            .all(|claim| Proofs::<T>::get(claim).map(|info| info.owner) == Some(caller.clone())));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_claims(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let claims = batch::<T>(n);
+
+        Pallet::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), claims.clone())?;
+
+        #[extrinsic_call]
+        revoke_claims(RawOrigin::Signed(caller), claims.clone());
+
+        assert!(claims.iter().all(|claim| Proofs::<T>::get(claim).is_none()));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_claims(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let target: T::AccountId = account("target", 0, 0);
+        funded::<T>(&caller);
+        let claims = batch::<T>(n);
+
+        Pallet::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), claims.clone())?;
+        let transfers: Vec<_> = claims.iter().map(|claim| (claim.clone(), target.clone())).collect();
+
+        #[extrinsic_call]
+        transfer_claims(RawOrigin::Signed(caller), BoundedVec::try_from(transfers).unwrap());
+
+        assert!(claims
+            .iter()
+            .all(|claim| PendingOffers::<T>::get(claim) == Some(target.clone())));
+
+        Ok(())
+    }
+
 }
 
 // cargo build --profile=production --features runtime-benchmarks
@@ -86,4 +755,4 @@ mod benches {
 // --steps 20 \
 // --repeat 10 \
 // --output pallets/poe/src/weights.rs \
-// --template .maintain/frame-weight-template.hbs
\ No newline at end of file
+// --template .maintain/frame-weight-template.hbs
@@ -0,0 +1,119 @@
+//! Transaction extension limiting how many claims an account creates.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, traits::IsSubType};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+    traits::{DispatchInfoOf, One, SignedExtension, Zero},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+};
+use sp_std::marker::PhantomData;
+
+/// `InvalidTransaction::Custom` code of transactions exceeding the claim rate of their signer.
+pub const CLAIM_RATE_EXCEEDED: u8 = 1;
+
+/// Rejects transactions that would make their signer create more than `MaxClaimsPerWindow`
+/// claims within a window of `ClaimRateWindow` blocks.
+///
+/// Windows are aligned on multiples of `ClaimRateWindow`. `validate` only counts the claims
+/// already created on-chain, not those of other transactions waiting in the pool, so a signer
+/// can still queue more than their share with consecutive nonces. The excess then fails
+/// `pre_dispatch` and is dropped from the block without being included.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct LimitClaimRate<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> LimitClaimRate<T> {
+    /// Creates new `SignedExtension` to limit the claim rate of the signer.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Start of the current window and the claims `who` already created in it.
+    fn usage(who: &T::AccountId) -> (BlockNumberFor<T>, u32) {
+        let now = frame_system::Pallet::<T>::block_number();
+        let window = T::ClaimRateWindow::get().max(One::one());
+        let start = now - now % window;
+
+        match ClaimRate::<T>::get(who) {
+            Some((last, used)) if last == start => (start, used),
+            _ => (start, 0),
+        }
+    }
+
+    /// Start of the current window and the claims `who` has used in it once `call` is included.
+    fn check(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+    ) -> Result<Option<(BlockNumberFor<T>, u32)>, TransactionValidityError>
+    where
+        <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+    {
+        let claims = call.is_sub_type().map_or(0, |call| call.claims_created());
+        if claims.is_zero() {
+            return Ok(None);
+        }
+
+        let (start, used) = Self::usage(who);
+        let used = used
+            .checked_add(claims)
+            .filter(|used| *used <= T::MaxClaimsPerWindow::get())
+            .ok_or(InvalidTransaction::Custom(CLAIM_RATE_EXCEEDED))?;
+
+        Ok(Some((start, used)))
+    }
+}
+
+impl<T: Config + Send + Sync> Default for LimitClaimRate<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for LimitClaimRate<T> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "LimitClaimRate")
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for LimitClaimRate<T>
+where
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+    const IDENTIFIER: &'static str = "LimitClaimRate";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        Self::check(who, call)?;
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Some(usage) = Self::check(who, call)? {
+            ClaimRate::<T>::insert(who, usage);
+        }
+        Ok(())
+    }
+}
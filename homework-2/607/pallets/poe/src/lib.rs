@@ -11,40 +11,677 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod claim_rate;
+pub mod migrations;
 pub mod weights;
+pub use claim_rate::LimitClaimRate;
 pub use weights::*;
 
+/// Signs `create_claim_for` payloads in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, Signature> {
+    /// A public key whose private key `sign` has access to.
+    fn signer() -> Public;
+    fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<sp_runtime::MultiSigner, sp_runtime::MultiSignature> for () {
+    fn signer() -> sp_runtime::MultiSigner {
+        sp_io::crypto::sr25519_generate(0.into(), None).into()
+    }
+
+    fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> sp_runtime::MultiSignature {
+        let public: sp_core::sr25519::Public = signer.clone().try_into().unwrap();
+        sp_io::crypto::sr25519_sign(0.into(), &public, message)
+            .unwrap()
+            .into()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        pallet_prelude::*,
+        storage::with_storage_layer,
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision, Restriction},
+            Time,
+        },
+        weights::WeightMeter,
+    };
     use frame_system::pallet_prelude::*;
+    use sp_core::H256;
+    use sp_runtime::traits::{Hash as HashT, IdentifyAccount, Saturating, Verify, Zero};
+    use sp_std::vec::Vec;
     use super::WeightInfo;
 
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+    /// Hash function a digest or Merkle tree was computed with.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum HashAlgorithm {
+        Blake2_256,
+        Keccak256,
+        Sha2_256,
+    }
+
+    impl HashAlgorithm {
+        /// Hash `data` into 32 bytes.
+        pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+            match self {
+                Self::Blake2_256 => sp_io::hashing::blake2_256(data),
+                Self::Keccak256 => sp_io::hashing::keccak_256(data),
+                Self::Sha2_256 => sp_io::hashing::sha2_256(data),
+            }
+        }
+
+        /// The bottom node of a Merkle tree for `leaf`: the hash of `0x00 ++ leaf`.
+        pub fn hash_leaf(&self, leaf: H256) -> H256 {
+            H256(self.hash(&[&[0u8][..], leaf.as_bytes()].concat()))
+        }
+
+        /// The parent of two Merkle tree nodes: the hash of `0x01 ++ left ++ right`.
+        ///
+        /// The prefixes differ from `hash_leaf`, so an internal node cannot pass as a leaf.
+        pub fn hash_node(&self, left: H256, right: H256) -> H256 {
+            H256(self.hash(&[&[1u8][..], left.as_bytes(), right.as_bytes()].concat()))
+        }
+
+        /// The root of the Merkle tree that has `leaf` at position `index`.
+        ///
+        /// `proof` holds the sibling nodes on the path from `leaf` up to the root. Bit `n` of
+        /// `index` tells whether the node at depth `n` is the left (0) or right (1) child.
+        pub fn merkle_root(&self, leaf: H256, index: u32, proof: &[H256]) -> H256 {
+            proof.iter().enumerate().fold(self.hash_leaf(leaf), |node, (depth, sibling)| {
+                if index.checked_shr(depth as u32).unwrap_or(0) & 1 == 0 {
+                    self.hash_node(node, *sibling)
+                } else {
+                    self.hash_node(*sibling, node)
+                }
+            })
+        }
+    }
+
+    /// What a claim stands for.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ClaimKind {
+        /// A single document.
+        Document,
+        /// The root of a Merkle tree over many documents.
+        MerkleRoot(HashAlgorithm),
+        /// The digest of a single document.
+        Digest(HashAlgorithm),
+    }
+
+    /// Optional description of the document behind a claim.
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct ClaimMetadata<T: Config> {
+        pub mime_type: Option<BoundedVec<u8, T::MaxMimeTypeLength>>,
+        pub uri: Option<BoundedVec<u8, T::MaxUriLength>>,
+    }
+
+    impl<T: Config> ClaimMetadata<T> {
+        /// Number of bytes the metadata takes up, as far as the deposit is concerned.
+        pub fn byte_len(&self) -> u32 {
+            let mime_type = self.mime_type.as_ref().map_or(0, |m| m.len());
+            let uri = self.uri.as_ref().map_or(0, |u| u.len());
+            (mime_type + uri) as u32
+        }
+    }
+
+    /// What is stored in `Proofs` for every claim.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ClaimInfo<AccountId, BlockNumber, Balance, Moment> {
+        pub owner: AccountId,
+        /// Block in which the claim was created or last changed hands.
+        pub block_number: BlockNumber,
+        /// Block in which the claim was created, or committed to if it was revealed. Unlike
+        /// `block_number`, it is kept when the claim changes hands.
+        pub created_at: BlockNumber,
+        /// Time of `block_number`, zero for claims made before timestamps were recorded.
+        pub timestamp: Moment,
+        /// Account the deposit is held from, the relayer for claims created on the owner's behalf.
+        pub depositor: AccountId,
+        /// Amount held from `depositor` for as long as the claim exists.
+        pub deposit: Balance,
+        /// Block at which the claim is removed again, if it is time-limited.
+        pub expires_at: Option<BlockNumber>,
+        pub kind: ClaimKind,
+    }
+
+    impl<AccountId, BlockNumber: PartialOrd, Balance, Moment>
+        ClaimInfo<AccountId, BlockNumber, Balance, Moment>
+    {
+        /// Whether the claim has expired by block `now`, whether or not it has been swept yet.
+        pub fn is_expired(&self, now: &BlockNumber) -> bool {
+            self.expires_at.as_ref().map_or(false, |expires_at| expires_at <= now)
+        }
+    }
+
+    pub type ClaimInfoOf<T> = ClaimInfo<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        BalanceOf<T>,
+        MomentOf<T>,
+    >;
+
+    /// A past owner of a claim.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ProvenanceRecord<AccountId, BlockNumber, Moment> {
+        pub owner: AccountId,
+        /// Block in which `owner` handed the claim on.
+        pub block_number: BlockNumber,
+        /// Time of `block_number`.
+        pub timestamp: Moment,
+    }
+
+    pub type ProvenanceRecordOf<T> =
+        ProvenanceRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, MomentOf<T>>;
+
+    /// What is left of a claim revoked with a reason.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Tombstone<AccountId, BlockNumber> {
+        pub revoker: AccountId,
+        pub reason: u16,
+        pub block_number: BlockNumber,
+        /// `created_at` of the revoked claim.
+        pub created_at: BlockNumber,
+    }
+
+    pub type TombstoneOf<T> = Tombstone<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// The reason code of the tombstone left by an upheld challenge, with the challenger as the
+    /// revoker.
+    pub const CHALLENGE_UPHELD_REASON: u16 = u16::MAX;
+
+    /// A challenge against a claim, waiting for the arbitrator.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Dispute<AccountId, BlockNumber, Balance> {
+        pub challenger: AccountId,
+        /// Amount held from `challenger` until the dispute is resolved.
+        pub bond: Balance,
+        pub block_number: BlockNumber,
+    }
+
+    pub type DisputeOf<T> =
+        Dispute<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+    /// How the arbitrator resolves a dispute.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Verdict {
+        /// The claim stands and the challenger's bond is slashed.
+        Rejected,
+        /// The claim is revoked, its deposit goes to the challenger and the bond is returned.
+        Upheld,
+    }
+
+    /// A hidden claim, waiting to be revealed.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Commitment<AccountId, BlockNumber, Balance, Moment> {
+        pub who: AccountId,
+        /// Block of the commitment, which becomes the block of the revealed claim.
+        pub block_number: BlockNumber,
+        /// Time of `block_number`, which becomes the timestamp of the revealed claim.
+        pub timestamp: Moment,
+        /// Amount held from `who` until the commitment is revealed or expires.
+        pub deposit: Balance,
+    }
+
+    pub type CommitmentOf<T> = Commitment<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        BalanceOf<T>,
+        MomentOf<T>,
+    >;
+
+    /// Accounts owning a claim together, `threshold` of which must approve revoking or
+    /// transferring it.
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct CoOwnership<T: Config> {
+        pub owners: BoundedVec<T::AccountId, T::MaxCoOwners>,
+        pub threshold: u32,
+    }
+
+    /// An action on a co-owned claim that needs the approval of its co-owners.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ClaimAction<AccountId> {
+        Revoke,
+        /// Revoke the claim, leaving a tombstone with the reason code.
+        RevokeWithReason(u16),
+        /// Offer the claim to the account, which still has to accept it.
+        Transfer(AccountId),
+    }
+
+    /// A namespace registered by `owner`, in which `owner` and `writers` can create claims.
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Namespace<T: Config> {
+        pub owner: T::AccountId,
+        /// Amount held from `owner` for as long as the namespace is registered.
+        pub deposit: BalanceOf<T>,
+        pub writers: BoundedVec<T::AccountId, T::MaxNamespaceWriters>,
+        /// Number of claims in the namespace, which must be zero to unregister it.
+        pub claims: u32,
+    }
+
+    /// Approvals collected so far for an action on a co-owned claim.
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Approval<T: Config> {
+        pub action: ClaimAction<T::AccountId>,
+        pub approvers: BoundedVec<T::AccountId, T::MaxCoOwners>,
+        /// Block from which the approvals are void.
+        pub expires_at: BlockNumberFor<T>,
+    }
+
+    /// What a delegate may do with a claim on its owner's behalf, as a set of bit flags.
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct DelegatePermissions(pub u8);
+
+    impl DelegatePermissions {
+        /// Revoke the claim, with or without a reason.
+        pub const REVOKE: Self = Self(1 << 0);
+        /// Offer the claim to another account.
+        pub const TRANSFER: Self = Self(1 << 1);
+        /// Every permission there is.
+        pub const ALL: Self = Self(Self::REVOKE.0 | Self::TRANSFER.0);
+
+        /// Whether every flag of `other` is set.
+        pub fn contains(&self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Whether at least one flag is set, and no unknown one.
+        pub fn is_valid(&self) -> bool {
+            self.0 != 0 && self.0 & !Self::ALL.0 == 0
+        }
+    }
+
+    impl core::ops::BitOr for DelegatePermissions {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    /// An account the owner of a claim authorized to act on it.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Delegate<AccountId, BlockNumber> {
+        pub who: AccountId,
+        pub permissions: DelegatePermissions,
+        /// Block from which the delegate can no longer act, if the delegation is time-limited.
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    impl<AccountId, BlockNumber: PartialOrd> Delegate<AccountId, BlockNumber> {
+        /// Whether the delegation still holds at block `now`.
+        pub fn is_active(&self, now: &BlockNumber) -> bool {
+            self.expires_at.as_ref().map_or(true, |expires_at| expires_at > now)
+        }
+    }
+
+    pub type DelegateOf<T> = Delegate<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// The `tag` of every `ClaimForPayload`.
+    pub const CLAIM_FOR_TAG: [u8; 13] = *b"poe/claim_for";
+
+    /// What an owner signs off-chain to have a relayer submit `create_claim_for` for them.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ClaimForPayload<AccountId, Claim, BlockNumber, Hash> {
+        /// Always `CLAIM_FOR_TAG`, so the signature cannot be passed off as one for another
+        /// message.
+        pub tag: [u8; 13],
+        /// Hash of the genesis block, so the signature is only good on this chain.
+        pub genesis_hash: Hash,
+        pub owner: AccountId,
+        pub claim: Claim,
+        /// The owner's current entry in `Nonces`.
+        pub nonce: u64,
+        /// Last block in which the payload can be submitted.
+        pub deadline: BlockNumber,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// The currency the claim deposits are held in.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// Deposit held for every claim, whatever its length.
+        #[pallet::constant]
+        type DepositBase: Get<BalanceOf<Self>>;
+        /// Deposit held for every byte of a claim.
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
         #[pallet::constant]
         type MaxClaimLength: Get<u32>;
+        /// Maximum number of claims a single account can own.
+        #[pallet::constant]
+        type MaxClaimsPerOwner: Get<u32>;
+        /// Maximum depth of the Merkle trees inclusion can be checked against.
+        #[pallet::constant]
+        type MaxProofDepth: Get<u32>;
+        /// Maximum length of the MIME type of a claim.
+        #[pallet::constant]
+        type MaxMimeTypeLength: Get<u32>;
+        /// Maximum length of the URI of a claim.
+        #[pallet::constant]
+        type MaxUriLength: Get<u32>;
+        /// Number of blocks a commitment can be revealed in before it expires.
+        #[pallet::constant]
+        type RevealWindow: Get<BlockNumberFor<Self>>;
+        /// Maximum number of co-owners of a claim.
+        #[pallet::constant]
+        type MaxCoOwners: Get<u32>;
+        /// Number of blocks approvals for an action on a co-owned claim are kept for.
+        #[pallet::constant]
+        type ApprovalTimeout: Get<BlockNumberFor<Self>>;
+        /// Maximum number of past owners kept for a claim; the oldest are dropped first.
+        #[pallet::constant]
+        type MaxProvenance: Get<u32>;
+        /// Bond held from an account challenging a claim.
+        #[pallet::constant]
+        type ChallengeBond: Get<BalanceOf<Self>>;
+        /// Origin that resolves disputes over claims.
+        type ArbitratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Deposit held for every registered namespace.
+        #[pallet::constant]
+        type NamespaceDeposit: Get<BalanceOf<Self>>;
+        #[pallet::constant]
+        type MaxNamespaceLength: Get<u32>;
+        /// Maximum number of writers a namespace owner can authorize.
+        #[pallet::constant]
+        type MaxNamespaceWriters: Get<u32>;
+        /// Maximum number of claims a batch call can handle.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// Maximum number of delegates of a claim.
+        #[pallet::constant]
+        type MaxDelegates: Get<u32>;
+        /// Length in blocks of the windows `LimitClaimRate` counts claims in.
+        #[pallet::constant]
+        type ClaimRateWindow: Get<BlockNumberFor<Self>>;
+        /// Claims an account may create per `ClaimRateWindow`.
+        #[pallet::constant]
+        type MaxClaimsPerWindow: Get<u32>;
+        /// Source of the timestamps recorded with claims and their provenance.
+        type Time: Time;
+        /// Signature an owner authorizes `create_claim_for` with.
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+        /// Public key of an `OffchainSignature`, identifying the owner's account.
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
         type WeightInfo: WeightInfo;
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: crate::BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Storage deposit of a claim.
+        ClaimDeposit,
+        /// Deposit of a commitment that has not been revealed yet.
+        CommitmentDeposit,
+        /// Bond of an account challenging a claim.
+        ChallengeBond,
+        /// Deposit of a registered namespace.
+        NamespaceDeposit,
+    }
+
     #[pallet::storage]
     pub type Proofs<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         BoundedVec<u8, T::MaxClaimLength>,
-        (T::AccountId, BlockNumberFor<T>),
+        ClaimInfoOf<T>,
+    >;
+
+    /// The claims owned by each account.
+    #[pallet::storage]
+    pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        (),
+    >;
+
+    /// Number of claims of each account in `ClaimsByOwner`, at most `MaxClaimsPerOwner`.
+    #[pallet::storage]
+    pub type OwnedClaimCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Time-limited claims, indexed by the block they expire at.
+    #[pallet::storage]
+    pub type ClaimExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        (),
+    >;
+
+    /// The first block of `ClaimExpiries` that has not been fully swept yet.
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// MIME type and URI of claims that were created with them.
+    #[pallet::storage]
+    pub type Metadata<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, ClaimMetadata<T>>;
+
+    /// Number of claims each account has had created for it through `create_claim_for`.
+    #[pallet::storage]
+    pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Commitments to claims, keyed by the hash of the claim and its salt.
+    #[pallet::storage]
+    pub type Commitments<T: Config> = StorageMap<_, Identity, T::Hash, CommitmentOf<T>>;
+
+    /// Commitments indexed by the block they expire at.
+    #[pallet::storage]
+    pub type CommitmentExpiries<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, T::Hash, ()>;
+
+    /// The first block of `CommitmentExpiries` that has not been fully swept yet.
+    #[pallet::storage]
+    pub type CommitmentCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Co-owners of the claims that are owned jointly.
+    #[pallet::storage]
+    pub type CoOwners<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, CoOwnership<T>>;
+
+    /// The action pending on each co-owned claim, with the approvals it has collected.
+    #[pallet::storage]
+    pub type Approvals<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, Approval<T>>;
+
+    /// Past owners of each claim that changed hands, oldest first.
+    #[pallet::storage]
+    pub type Provenance<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        BoundedVec<ProvenanceRecordOf<T>, T::MaxProvenance>,
+        ValueQuery,
+    >;
+
+    /// Claims that were revoked with a reason; they cannot be created again, except by the
+    /// challenger whose challenge was upheld.
+    #[pallet::storage]
+    pub type Revocations<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, TombstoneOf<T>>;
+
+    /// The account each claim has been offered to, waiting for it to accept.
+    #[pallet::storage]
+    pub type PendingOffers<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, T::AccountId>;
+
+    /// Open challenges against claims.
+    #[pallet::storage]
+    pub type Disputes<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxClaimLength>, DisputeOf<T>>;
+
+    #[pallet::storage]
+    pub type Namespaces<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxNamespaceLength>, Namespace<T>>;
+
+    /// Claims created within a namespace, separate from the global `Proofs`.
+    #[pallet::storage]
+    pub type NamespacedProofs<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxNamespaceLength>,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        ClaimInfoOf<T>,
+    >;
+
+    /// Accounts that can revoke or transfer each claim on its owner's behalf.
+    #[pallet::storage]
+    pub type Delegates<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxClaimLength>,
+        BoundedVec<DelegateOf<T>, T::MaxDelegates>,
+        ValueQuery,
     >;
 
+    /// Start of the window an account last created claims in, with the number it created.
+    ///
+    /// Kept by the `LimitClaimRate` transaction extension, not by the calls themselves.
+    #[pallet::storage]
+    pub type ClaimRate<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32)>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
         ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-        ClaimTransferred(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+        /// A claim was revoked with a reason code and left a tombstone.
+        RevocationRecorded(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, u16),
+        ClaimTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        },
+        /// The expiry of a claim was changed; `None` means it no longer expires.
+        ClaimRenewed(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, Option<BlockNumberFor<T>>),
+        ClaimExpired(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+        /// The owner offered a claim to another account.
+        ClaimOffered(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+        OfferCancelled(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+        /// A leaf was proven to be part of an anchored Merkle root.
+        InclusionVerified(H256, H256),
+        ClaimCommitted(T::AccountId, T::Hash),
+        /// A committed claim was revealed, with the block of its commitment.
+        ClaimRevealed(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, BlockNumberFor<T>),
+        CommitmentExpired(T::AccountId, T::Hash),
+        /// The owner shared a claim with co-owners, with the number of approvals required.
+        CoOwnersSet(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, u32),
+        /// A co-owner approved an action, with the number of approvals it has so far.
+        ActionApproved(
+            T::AccountId,
+            BoundedVec<u8, T::MaxClaimLength>,
+            ClaimAction<T::AccountId>,
+            u32,
+        ),
+        /// A claim was challenged, with the bond held from the challenger.
+        ClaimChallenged(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, BalanceOf<T>),
+        /// The arbitrator rejected a challenge, with the amount slashed from the challenger.
+        ChallengeRejected(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, BalanceOf<T>),
+        /// The arbitrator upheld a challenge, with the deposit of the claim paid to the
+        /// challenger.
+        ChallengeUpheld(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, BalanceOf<T>),
+        /// A challenged claim expired, so the bond was returned to the challenger.
+        ChallengeDropped(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+        NamespaceRegistered(T::AccountId, BoundedVec<u8, T::MaxNamespaceLength>),
+        NamespaceUnregistered(T::AccountId, BoundedVec<u8, T::MaxNamespaceLength>),
+        /// The owner of a namespace authorized an account to create claims in it.
+        WriterAdded(BoundedVec<u8, T::MaxNamespaceLength>, T::AccountId),
+        WriterRemoved(BoundedVec<u8, T::MaxNamespaceLength>, T::AccountId),
+        NamespacedClaimCreated(
+            T::AccountId,
+            BoundedVec<u8, T::MaxNamespaceLength>,
+            BoundedVec<u8, T::MaxClaimLength>,
+        ),
+        NamespacedClaimRevoked(
+            T::AccountId,
+            BoundedVec<u8, T::MaxNamespaceLength>,
+            BoundedVec<u8, T::MaxClaimLength>,
+        ),
+        /// A batch call finished, with the outcome of each of its items in order.
+        BatchCompleted(T::AccountId, Vec<Result<(), DispatchError>>),
+        /// The owner of a claim authorized a delegate, with its permissions and expiry.
+        DelegateAdded(
+            BoundedVec<u8, T::MaxClaimLength>,
+            T::AccountId,
+            DelegatePermissions,
+            Option<BlockNumberFor<T>>,
+        ),
+        DelegateRemoved(BoundedVec<u8, T::MaxClaimLength>, T::AccountId),
     }
 
     #[pallet::error]
@@ -52,6 +689,87 @@ pub mod pallet {
         ProofAlreadyExist,
         ClaimNotExist,
         NotClaimOwner,
+        /// The expiry block is not in the future.
+        ExpiryInPast,
+        /// The claim has expired and is waiting to be swept.
+        ClaimExpired,
+        /// The claim has not been offered to anyone.
+        OfferNotExist,
+        /// The claim has been offered to another account.
+        NotOfferTarget,
+        /// A claim cannot be offered to its own owner.
+        OfferToSelf,
+        /// The account already owns `MaxClaimsPerOwner` claims.
+        TooManyClaims,
+        /// The claim is longer than `MaxClaimLength`.
+        ClaimTooLong,
+        /// The claim is not a Merkle root.
+        NotMerkleRoot,
+        /// The proof does not lead from the leaf to the Merkle root.
+        InvalidProof,
+        /// The deadline of a signed payload has passed.
+        DeadlineExpired,
+        /// The signature does not match the owner and payload.
+        BadSignature,
+        CommitmentAlreadyExist,
+        /// No commitment matches the claim and salt.
+        CommitmentNotExist,
+        NotCommitter,
+        /// The reveal window of the commitment has passed.
+        CommitmentExpired,
+        /// The claim is co-owned, so the action needs to be approved by its co-owners.
+        ApprovalRequired,
+        /// The claim already has co-owners.
+        AlreadyCoOwned,
+        /// The co-owners do not include the owner, contain duplicates, or the threshold is not
+        /// between 1 and the number of co-owners.
+        InvalidCoOwners,
+        NotCoOwner,
+        AlreadyApproved,
+        /// Another action on the claim is still collecting approvals.
+        OtherActionPending,
+        /// The claim was revoked and left a tombstone.
+        ClaimRevoked,
+        /// The claim is being disputed, so it cannot be challenged, revoked or transferred.
+        ClaimDisputed,
+        /// The claim is not being disputed.
+        DisputeNotExist,
+        /// An owner cannot challenge their own claim.
+        ChallengeOwnClaim,
+        NamespaceAlreadyExist,
+        NamespaceNotExist,
+        NotNamespaceOwner,
+        /// The caller is neither the owner nor an authorized writer of the namespace.
+        NotNamespaceWriter,
+        /// The account is already a writer of the namespace.
+        AlreadyWriter,
+        /// The namespace already has `MaxNamespaceWriters` writers.
+        TooManyWriters,
+        /// The namespace still holds claims.
+        NamespaceNotEmpty,
+        /// No permission is set, or an unknown one is.
+        InvalidPermissions,
+        /// An owner cannot be their own delegate.
+        DelegateToSelf,
+        /// The account is not a delegate of the claim.
+        NotDelegate,
+        /// The claim already has `MaxDelegates` delegates.
+        TooManyDelegates,
+        /// The claim has been offered to someone, and the offer has to be cancelled first.
+        OfferPending,
+        /// `CHALLENGE_UPHELD_REASON` is only left by upheld challenges.
+        ReservedReason,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::sweep_expired_claims(now, remaining_weight);
+            used.saturating_add(Self::sweep_expired_commitments(
+                now,
+                remaining_weight.saturating_sub(used),
+            ))
+        }
     }
 
     #[pallet::call]
@@ -64,19 +782,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
-            ensure!(
-                !Proofs::<T>::contains_key(&claim),
-                Error::<T>::ProofAlreadyExist
-            );
-
-            Proofs::<T>::insert(
-                &claim,                                                      //key
-                (sender.clone(), frame_system::Pallet::<T>::block_number()), //value
-            );
-
-            Self::deposit_event(Event::ClaimCreated(sender, claim));
-
-            Ok(().into())
+            Self::do_create_claim(sender.clone(), sender, claim, ClaimKind::Document, None, None)
         }
 
         #[pallet::call_index(1)]
@@ -86,37 +792,1403 @@ pub mod pallet {
             claim: BoundedVec<u8, T::MaxClaimLength>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
 
-            ensure!(owner == sender, Error::<T>::NotClaimOwner);
+            Self::do_revoke_owned_claim(sender, claim, None)
+        }
 
-            Proofs::<T>::remove(&claim);
+        /// Offer the claim to `dest`, same as `offer_claim`.
+        ///
+        /// The owner only changes once `dest` calls `accept_claim`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::offer_claim(claim.len() as u32))]
+        pub fn transfer_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_offer_claim(sender, claim, dest)
+        }
+
+        /// Create a claim that is removed again, and its deposit released, at `expires_at`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::create_claim_with_expiry(claim.len() as u32))]
+        pub fn create_claim_with_expiry(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_create_claim(
+                sender.clone(),
+                sender,
+                claim,
+                ClaimKind::Document,
+                None,
+                Some(expires_at),
+            )
+        }
+
+        /// Move the expiry of a claim to `expires_at`, or make it permanent with `None`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::renew_claim(claim.len() as u32))]
+        pub fn renew_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let mut info = Self::live_claim(&claim)?;
 
-            Self::deposit_event(Event::ClaimRevoked(sender, claim));
+            ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(old) = info.expires_at {
+                ClaimExpiries::<T>::remove(old, &claim);
+            }
+            if let Some(new) = expires_at {
+                ensure!(new > now, Error::<T>::ExpiryInPast);
+                ClaimExpiries::<T>::insert(new, &claim, ());
+            }
+
+            info.expires_at = expires_at;
+            Proofs::<T>::insert(&claim, info);
+
+            Self::deposit_event(Event::ClaimRenewed(sender, claim, expires_at));
 
             Ok(().into())
         }
 
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::transfer_claim(claim.len() as u32))]
-        pub fn transfer_claim(
+        /// Offer the claim to `dest`, replacing any earlier offer.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::offer_claim(claim.len() as u32))]
+        pub fn offer_claim(
             origin: OriginFor<T>,
             claim: BoundedVec<u8, T::MaxClaimLength>,
             dest: T::AccountId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
-            ensure!(Proofs::<T>::contains_key(&claim), Error::<T>::ClaimNotExist);
+            Self::do_offer_claim(sender, claim, dest)
+        }
+
+        /// Take over a claim that was offered to the caller, together with its deposit.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::accept_claim(claim.len() as u32))]
+        pub fn accept_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let target = PendingOffers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+
+            ensure!(target == sender, Error::<T>::NotOfferTarget);
+
+            let info = Self::live_claim(&claim)?;
+            PendingOffers::<T>::remove(&claim);
+
+            Self::do_transfer_claim(claim, info, sender)
+        }
+
+        /// Withdraw a pending offer, as the owner or a delegate allowed to transfer the claim.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::cancel_offer(claim.len() as u32))]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Self::live_claim(&claim)?;
+
+            Self::ensure_can_act(&claim, &info, &sender, DelegatePermissions::TRANSFER)?;
+            ensure!(PendingOffers::<T>::contains_key(&claim), Error::<T>::OfferNotExist);
+
+            PendingOffers::<T>::remove(&claim);
+
+            Self::deposit_event(Event::OfferCancelled(info.owner, claim));
+
+            Ok(().into())
+        }
+
+        /// Anchor `root`, the root of a Merkle tree built with `algorithm`, as a claim.
+        ///
+        /// The tree must be built with `HashAlgorithm::hash_leaf` and `HashAlgorithm::hash_node`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::create_claim(H256::len_bytes() as u32))]
+        pub fn anchor_merkle_root(
+            origin: OriginFor<T>,
+            root: H256,
+            algorithm: HashAlgorithm,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let claim = Self::hash_claim(&root)?;
 
-            let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+            Self::do_create_claim(
+                sender.clone(),
+                sender,
+                claim,
+                ClaimKind::MerkleRoot(algorithm),
+                None,
+                None,
+            )
+        }
 
-            ensure!(owner == sender, Error::<T>::NotClaimOwner);
+        /// Check that `leaf` is leaf number `index` of the anchored Merkle root `root`.
+        ///
+        /// `proof` holds the sibling hashes on the path from `leaf` up to `root`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::verify_inclusion(proof.len() as u32))]
+        pub fn verify_inclusion(
+            origin: OriginFor<T>,
+            root: H256,
+            leaf: H256,
+            index: u32,
+            proof: BoundedVec<H256, T::MaxProofDepth>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
 
-            Proofs::<T>::insert(&claim, (dest, frame_system::Pallet::<T>::block_number()));
+            Self::do_verify_inclusion(root, leaf, index, &proof)?;
 
-            Self::deposit_event(Event::ClaimTransferred(sender, claim));
+            Self::deposit_event(Event::InclusionVerified(root, leaf));
 
             Ok(().into())
         }
+
+        /// Claim the document whose `algorithm` digest is `digest`.
+        ///
+        /// The claim is keyed by the digest, so its size does not depend on the document.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::create_digest_claim(
+            mime_type.as_ref().map_or(0, |m| m.len() as u32),
+            uri.as_ref().map_or(0, |u| u.len() as u32),
+        ))]
+        pub fn create_digest_claim(
+            origin: OriginFor<T>,
+            digest: H256,
+            algorithm: HashAlgorithm,
+            mime_type: Option<BoundedVec<u8, T::MaxMimeTypeLength>>,
+            uri: Option<BoundedVec<u8, T::MaxUriLength>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let claim = Self::hash_claim(&digest)?;
+            let metadata =
+                (mime_type.is_some() || uri.is_some()).then_some(ClaimMetadata { mime_type, uri });
+
+            Self::do_create_claim(
+                sender.clone(),
+                sender,
+                claim,
+                ClaimKind::Digest(algorithm),
+                metadata,
+                None,
+            )
+        }
+
+        /// Create a claim for `owner`, who signed a `ClaimForPayload` for it off-chain.
+        ///
+        /// The caller relays the claim, paying the fee and the deposit.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::create_claim_for(claim.len() as u32))]
+        pub fn create_claim_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            signature: T::OffchainSignature,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= deadline,
+                Error::<T>::DeadlineExpired
+            );
+
+            let nonce = Nonces::<T>::get(&owner);
+            let payload = ClaimForPayload {
+                tag: CLAIM_FOR_TAG,
+                genesis_hash: frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+                owner: owner.clone(),
+                claim: claim.clone(),
+                nonce,
+                deadline,
+            };
+            ensure!(
+                signature.verify(&payload.encode()[..], &owner),
+                Error::<T>::BadSignature
+            );
+            // Bumping the nonce makes the signature useless for any later call.
+            Nonces::<T>::insert(&owner, nonce.wrapping_add(1));
+
+            Self::do_create_claim(owner, relayer, claim, ClaimKind::Document, None, None)
+        }
+
+        /// Commit to a claim without publishing it.
+        ///
+        /// `salted_hash` is the hash of the SCALE-encoded `(claim, salt)` pair. The commitment
+        /// has to be revealed with `reveal_claim` within `RevealWindow` blocks.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::commit_claim())]
+        pub fn commit_claim(origin: OriginFor<T>, salted_hash: T::Hash) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(
+                !Commitments::<T>::contains_key(salted_hash),
+                Error::<T>::CommitmentAlreadyExist
+            );
+
+            let deposit = T::DepositBase::get();
+            T::Currency::hold(&HoldReason::CommitmentDeposit.into(), &sender, deposit)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = now.saturating_add(T::RevealWindow::get());
+            CommitmentExpiries::<T>::insert(expires_at, salted_hash, ());
+            Commitments::<T>::insert(
+                salted_hash,
+                Commitment {
+                    who: sender.clone(),
+                    block_number: now,
+                    timestamp: T::Time::now(),
+                    deposit,
+                },
+            );
+
+            Self::deposit_event(Event::ClaimCommitted(sender, salted_hash));
+
+            Ok(().into())
+        }
+
+        /// Publish a committed claim, dated at the block of its commitment.
+        ///
+        /// A claim created by anyone after the commitment is revoked, and the tombstone of such a
+        /// claim is cleared.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::reveal_claim(claim.len() as u32))]
+        pub fn reveal_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            salt: H256,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let salted_hash = T::Hashing::hash_of(&(&claim, &salt));
+            let commitment =
+                Commitments::<T>::get(salted_hash).ok_or(Error::<T>::CommitmentNotExist)?;
+
+            ensure!(commitment.who == sender, Error::<T>::NotCommitter);
+
+            let expires_at = commitment.block_number.saturating_add(T::RevealWindow::get());
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < expires_at,
+                Error::<T>::CommitmentExpired
+            );
+
+            T::Currency::release(
+                &HoldReason::CommitmentDeposit.into(),
+                &sender,
+                commitment.deposit,
+                Precision::BestEffort,
+            )?;
+            Commitments::<T>::remove(salted_hash);
+            CommitmentExpiries::<T>::remove(expires_at, salted_hash);
+
+            // Anyone who created the claim after the commitment, e.g. by copying it from this
+            // reveal while it waited in the pool, came second and loses it, together with
+            // whoever they have since shared it with or handed it to.
+            if let Some(info) = Proofs::<T>::get(&claim) {
+                if info.created_at > commitment.block_number {
+                    if let Some(dispute) = Disputes::<T>::take(&claim) {
+                        T::Currency::release(
+                            &HoldReason::ChallengeBond.into(),
+                            &dispute.challenger,
+                            dispute.bond,
+                            Precision::BestEffort,
+                        )?;
+                    }
+                    Self::do_revoke_claim(claim.clone(), info, None)?;
+                }
+            }
+            if Revocations::<T>::get(&claim)
+                .map_or(false, |tombstone| tombstone.created_at > commitment.block_number)
+            {
+                Revocations::<T>::remove(&claim);
+            }
+
+            Self::do_create_claim(
+                sender.clone(),
+                sender.clone(),
+                claim.clone(),
+                ClaimKind::Document,
+                None,
+                None,
+            )?;
+            // The claim existed, hidden, since the commitment.
+            Proofs::<T>::mutate(&claim, |info| {
+                if let Some(info) = info {
+                    info.block_number = commitment.block_number;
+                    info.created_at = commitment.block_number;
+                    info.timestamp = commitment.timestamp;
+                }
+            });
+
+            Self::deposit_event(Event::ClaimRevealed(sender, claim, commitment.block_number));
+
+            Ok(().into())
+        }
+
+        /// Share the claim with `owners`, which must include the caller.
+        ///
+        /// From then on `threshold` of them have to approve revoking or transferring the claim
+        /// through `approve_action`. A pending offer has to be cancelled first, so that it cannot
+        /// be accepted without their approval.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_co_owners(owners.len() as u32))]
+        pub fn set_co_owners(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            owners: BoundedVec<T::AccountId, T::MaxCoOwners>,
+            threshold: u32,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Self::live_claim(&claim)?;
+
+            ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(!CoOwners::<T>::contains_key(&claim), Error::<T>::AlreadyCoOwned);
+            ensure!(!PendingOffers::<T>::contains_key(&claim), Error::<T>::OfferPending);
+
+            let mut unique = owners.clone().into_inner();
+            unique.sort();
+            unique.dedup();
+            ensure!(
+                unique.len() == owners.len()
+                    && owners.contains(&sender)
+                    && threshold >= 1
+                    && threshold as usize <= owners.len(),
+                Error::<T>::InvalidCoOwners
+            );
+
+            CoOwners::<T>::insert(&claim, CoOwnership { owners, threshold });
+
+            Self::deposit_event(Event::CoOwnersSet(sender, claim, threshold));
+
+            Ok(().into())
+        }
+
+        /// Approve `action` on a co-owned claim, carrying it out once enough co-owners have.
+        ///
+        /// Approvals lapse `ApprovalTimeout` blocks after the first one.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::approve_action(T::MaxCoOwners::get()))]
+        pub fn approve_action(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            action: ClaimAction<T::AccountId>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Self::live_claim(&claim)?;
+            let co_owners = CoOwners::<T>::get(&claim).ok_or(Error::<T>::NotCoOwner)?;
+
+            ensure!(co_owners.owners.contains(&sender), Error::<T>::NotCoOwner);
+            if let ClaimAction::Transfer(dest) = &action {
+                ensure!(*dest != info.owner, Error::<T>::OfferToSelf);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut approval = match Approvals::<T>::get(&claim) {
+                Some(approval) if approval.expires_at > now => {
+                    ensure!(approval.action == action, Error::<T>::OtherActionPending);
+                    ensure!(!approval.approvers.contains(&sender), Error::<T>::AlreadyApproved);
+                    approval
+                }
+                _ => Approval {
+                    action: action.clone(),
+                    approvers: BoundedVec::new(),
+                    expires_at: now.saturating_add(T::ApprovalTimeout::get()),
+                },
+            };
+            // Cannot fail, the approvers are distinct co-owners.
+            approval
+                .approvers
+                .try_push(sender.clone())
+                .map_err(|_| Error::<T>::NotCoOwner)?;
+            let approvals = approval.approvers.len() as u32;
+
+            Self::deposit_event(Event::ActionApproved(
+                sender.clone(),
+                claim.clone(),
+                action,
+                approvals,
+            ));
+
+            if approvals < co_owners.threshold {
+                Approvals::<T>::insert(&claim, approval);
+                return Ok(().into());
+            }
+
+            Approvals::<T>::remove(&claim);
+            match approval.action {
+                ClaimAction::Revoke => Self::do_revoke_claim(claim, info, None),
+                ClaimAction::RevokeWithReason(reason) => {
+                    Self::do_revoke_claim(claim, info, Some((sender, reason)))
+                }
+                ClaimAction::Transfer(dest) => {
+                    PendingOffers::<T>::insert(&claim, &dest);
+                    Self::deposit_event(Event::ClaimOffered(info.owner, dest, claim));
+                    Ok(().into())
+                }
+            }
+        }
+
+        /// Revoke the claim, keeping a tombstone with `reason`, the caller and the block.
+        ///
+        /// Unlike `revoke_claim`, verifiers can tell the claim was revoked rather than never
+        /// made, and the claim cannot be created again.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::revoke_claim_with_reason(claim.len() as u32))]
+        pub fn revoke_claim_with_reason(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: u16,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::do_revoke_owned_claim(sender, claim, Some(reason))
+        }
+
+        /// Challenge a claim, holding `ChallengeBond` from the caller until `ArbitratorOrigin`
+        /// resolves the dispute.
+        ///
+        /// The claim cannot be revoked or transferred while it is disputed.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::challenge_claim(claim.len() as u32))]
+        pub fn challenge_claim(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Self::live_claim(&claim)?;
+
+            ensure!(info.owner != sender, Error::<T>::ChallengeOwnClaim);
+            ensure!(!Disputes::<T>::contains_key(&claim), Error::<T>::ClaimDisputed);
+
+            let bond = T::ChallengeBond::get();
+            T::Currency::hold(&HoldReason::ChallengeBond.into(), &sender, bond)?;
+
+            Disputes::<T>::insert(
+                &claim,
+                Dispute {
+                    challenger: sender.clone(),
+                    bond,
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::ClaimChallenged(sender, claim, bond));
+
+            Ok(().into())
+        }
+
+        /// Resolve the dispute over a claim.
+        ///
+        /// A rejected challenge burns the challenger's bond. An upheld one revokes the claim,
+        /// pays its deposit to the challenger and returns the bond, leaving a tombstone with
+        /// `CHALLENGE_UPHELD_REASON` so that only the challenger can create the claim again.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::resolve_dispute(claim.len() as u32))]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            verdict: Verdict,
+        ) -> DispatchResult {
+            T::ArbitratorOrigin::ensure_origin(origin)?;
+            let dispute = Disputes::<T>::take(&claim).ok_or(Error::<T>::DisputeNotExist)?;
+
+            match verdict {
+                Verdict::Rejected => {
+                    let slashed = T::Currency::burn_held(
+                        &HoldReason::ChallengeBond.into(),
+                        &dispute.challenger,
+                        dispute.bond,
+                        Precision::BestEffort,
+                        Fortitude::Force,
+                    )?;
+
+                    Self::deposit_event(Event::ChallengeRejected(
+                        dispute.challenger,
+                        claim,
+                        slashed,
+                    ));
+                }
+                Verdict::Upheld => {
+                    let info = Proofs::<T>::take(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+                    T::Currency::release(
+                        &HoldReason::ChallengeBond.into(),
+                        &dispute.challenger,
+                        dispute.bond,
+                        Precision::BestEffort,
+                    )?;
+                    let paid = T::Currency::transfer_on_hold(
+                        &HoldReason::ClaimDeposit.into(),
+                        &info.depositor,
+                        &dispute.challenger,
+                        info.deposit,
+                        Precision::BestEffort,
+                        Restriction::Free,
+                        Fortitude::Force,
+                    )?;
+                    Self::remove_claim_data(&claim);
+                    Self::unindex_claim(&info.owner, &claim);
+                    if let Some(expires_at) = info.expires_at {
+                        ClaimExpiries::<T>::remove(expires_at, &claim);
+                    }
+
+                    Revocations::<T>::insert(
+                        &claim,
+                        Tombstone {
+                            revoker: dispute.challenger.clone(),
+                            reason: CHALLENGE_UPHELD_REASON,
+                            block_number: frame_system::Pallet::<T>::block_number(),
+                            created_at: info.created_at,
+                        },
+                    );
+
+                    Self::deposit_event(Event::ClaimRevoked(info.owner, claim.clone()));
+                    Self::deposit_event(Event::RevocationRecorded(
+                        dispute.challenger.clone(),
+                        claim.clone(),
+                        CHALLENGE_UPHELD_REASON,
+                    ));
+                    Self::deposit_event(Event::ChallengeUpheld(dispute.challenger, claim, paid));
+                }
+            }
+
+            Ok(().into())
+        }
+
+        /// Register `namespace`, holding `NamespaceDeposit` from the caller until it is
+        /// unregistered.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::register_namespace())]
+        pub fn register_namespace(
+            origin: OriginFor<T>,
+            namespace: BoundedVec<u8, T::MaxNamespaceLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(
+                !Namespaces::<T>::contains_key(&namespace),
+                Error::<T>::NamespaceAlreadyExist
+            );
+
+            let deposit = T::NamespaceDeposit::get();
+            T::Currency::hold(&HoldReason::NamespaceDeposit.into(), &sender, deposit)?;
+
+            Namespaces::<T>::insert(
+                &namespace,
+                Namespace {
+                    owner: sender.clone(),
+                    deposit,
+                    writers: BoundedVec::new(),
+                    claims: 0,
+                },
+            );
+
+            Self::deposit_event(Event::NamespaceRegistered(sender, namespace));
+
+            Ok(().into())
+        }
+
+        /// Give up an empty namespace, releasing its deposit.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::unregister_namespace())]
+        pub fn unregister_namespace(
+            origin: OriginFor<T>,
+            namespace: BoundedVec<u8, T::MaxNamespaceLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Namespaces::<T>::get(&namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+
+            ensure!(info.owner == sender, Error::<T>::NotNamespaceOwner);
+            ensure!(info.claims == 0, Error::<T>::NamespaceNotEmpty);
+
+            T::Currency::release(
+                &HoldReason::NamespaceDeposit.into(),
+                &sender,
+                info.deposit,
+                Precision::BestEffort,
+            )?;
+            Namespaces::<T>::remove(&namespace);
+
+            Self::deposit_event(Event::NamespaceUnregistered(sender, namespace));
+
+            Ok(().into())
+        }
+
+        /// Let `who` create claims in the caller's namespace.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::add_writer(T::MaxNamespaceWriters::get()))]
+        pub fn add_writer(
+            origin: OriginFor<T>,
+            namespace: BoundedVec<u8, T::MaxNamespaceLength>,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Namespaces::<T>::try_mutate(&namespace, |info| -> DispatchResult {
+                let info = info.as_mut().ok_or(Error::<T>::NamespaceNotExist)?;
+
+                ensure!(info.owner == sender, Error::<T>::NotNamespaceOwner);
+                ensure!(
+                    who != info.owner && !info.writers.contains(&who),
+                    Error::<T>::AlreadyWriter
+                );
+
+                info.writers
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::TooManyWriters)?;
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WriterAdded(namespace, who));
+
+            Ok(().into())
+        }
+
+        /// Stop `who` from creating claims in the caller's namespace.
+        ///
+        /// Claims `who` already created stay in place.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::remove_writer(T::MaxNamespaceWriters::get()))]
+        pub fn remove_writer(
+            origin: OriginFor<T>,
+            namespace: BoundedVec<u8, T::MaxNamespaceLength>,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Namespaces::<T>::try_mutate(&namespace, |info| -> DispatchResult {
+                let info = info.as_mut().ok_or(Error::<T>::NamespaceNotExist)?;
+
+                ensure!(info.owner == sender, Error::<T>::NotNamespaceOwner);
+
+                let index = info
+                    .writers
+                    .iter()
+                    .position(|writer| *writer == who)
+                    .ok_or(Error::<T>::NotNamespaceWriter)?;
+                info.writers.remove(index);
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::WriterRemoved(namespace, who));
+
+            Ok(().into())
+        }
+
+        /// Create `claim` within `namespace`, which the caller must own or be a writer of.
+        ///
+        /// The same claim can exist in any number of namespaces and in `Proofs`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::create_namespaced_claim(claim.len() as u32))]
+        pub fn create_namespaced_claim(
+            origin: OriginFor<T>,
+            namespace: BoundedVec<u8, T::MaxNamespaceLength>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let mut info = Namespaces::<T>::get(&namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+
+            ensure!(
+                info.owner == sender || info.writers.contains(&sender),
+                Error::<T>::NotNamespaceWriter
+            );
+            ensure!(
+                !NamespacedProofs::<T>::contains_key(&namespace, &claim),
+                Error::<T>::ProofAlreadyExist
+            );
+
+            let deposit = Self::deposit_for(claim.len() as u32);
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &sender, deposit)?;
+
+            info.claims.saturating_inc();
+            Namespaces::<T>::insert(&namespace, info);
+            NamespacedProofs::<T>::insert(
+                &namespace,
+                &claim,
+                ClaimInfo {
+                    owner: sender.clone(),
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                    created_at: frame_system::Pallet::<T>::block_number(),
+                    timestamp: T::Time::now(),
+                    depositor: sender.clone(),
+                    deposit,
+                    expires_at: None,
+                    kind: ClaimKind::Document,
+                },
+            );
+
+            Self::deposit_event(Event::NamespacedClaimCreated(sender, namespace, claim));
+
+            Ok(().into())
+        }
+
+        /// Revoke `claim` within `namespace`, as its creator or as the owner of the namespace.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::revoke_namespaced_claim(claim.len() as u32))]
+        pub fn revoke_namespaced_claim(
+            origin: OriginFor<T>,
+            namespace: BoundedVec<u8, T::MaxNamespaceLength>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let claim_info = NamespacedProofs::<T>::get(&namespace, &claim)
+                .ok_or(Error::<T>::ClaimNotExist)?;
+            let mut info = Namespaces::<T>::get(&namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+
+            ensure!(
+                claim_info.owner == sender || info.owner == sender,
+                Error::<T>::NotClaimOwner
+            );
+
+            T::Currency::release(
+                &HoldReason::ClaimDeposit.into(),
+                &claim_info.depositor,
+                claim_info.deposit,
+                Precision::BestEffort,
+            )?;
+
+            info.claims.saturating_dec();
+            Namespaces::<T>::insert(&namespace, info);
+            NamespacedProofs::<T>::remove(&namespace, &claim);
+
+            Self::deposit_event(Event::NamespacedClaimRevoked(claim_info.owner, namespace, claim));
+
+            Ok(().into())
+        }
+
+        /// Create each of `claims`, as `create_claim` would.
+        ///
+        /// A claim that cannot be created does not stop the others; `BatchCompleted` reports
+        /// the outcome of each.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let results = claims
+                .into_iter()
+                .map(|claim| {
+                    with_storage_layer(|| {
+                        Self::do_create_claim(
+                            sender.clone(),
+                            sender.clone(),
+                            claim,
+                            ClaimKind::Document,
+                            None,
+                            None,
+                        )
+                    })
+                })
+                .collect();
+
+            Self::deposit_event(Event::BatchCompleted(sender, results));
+
+            Ok(().into())
+        }
+
+        /// Revoke each of `claims`, as `revoke_claim` would.
+        ///
+        /// A claim that cannot be revoked does not stop the others; `BatchCompleted` reports
+        /// the outcome of each.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::revoke_claims(claims.len() as u32))]
+        pub fn revoke_claims(
+            origin: OriginFor<T>,
+            claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatchSize>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let results = claims
+                .into_iter()
+                .map(|claim| {
+                    with_storage_layer(|| Self::do_revoke_owned_claim(sender.clone(), claim, None))
+                })
+                .collect();
+
+            Self::deposit_event(Event::BatchCompleted(sender, results));
+
+            Ok(().into())
+        }
+
+        /// Offer each claim to its destination, as `transfer_claim` would.
+        ///
+        /// A claim that cannot be offered does not stop the others; `BatchCompleted` reports
+        /// the outcome of each.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::transfer_claims(transfers.len() as u32))]
+        pub fn transfer_claims(
+            origin: OriginFor<T>,
+            transfers: BoundedVec<
+                (BoundedVec<u8, T::MaxClaimLength>, T::AccountId),
+                T::MaxBatchSize,
+            >,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let results = transfers
+                .into_iter()
+                .map(|(claim, dest)| {
+                    with_storage_layer(|| Self::do_offer_claim(sender.clone(), claim, dest))
+                })
+                .collect();
+
+            Self::deposit_event(Event::BatchCompleted(sender, results));
+
+            Ok(().into())
+        }
+
+        /// Let `who` revoke or transfer the caller's claim, as far as `permissions` allow and
+        /// until `expires_at` if given.
+        ///
+        /// Adding a delegate again replaces its permissions and expiry. Delegates are dropped
+        /// when the claim changes hands.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::add_delegate(T::MaxDelegates::get()))]
+        pub fn add_delegate(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            who: T::AccountId,
+            permissions: DelegatePermissions,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Self::live_claim(&claim)?;
+
+            ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+            ensure!(who != sender, Error::<T>::DelegateToSelf);
+            ensure!(!CoOwners::<T>::contains_key(&claim), Error::<T>::ApprovalRequired);
+            ensure!(permissions.is_valid(), Error::<T>::InvalidPermissions);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at > now, Error::<T>::ExpiryInPast);
+            }
+
+            Delegates::<T>::try_mutate(&claim, |delegates| -> DispatchResult {
+                // Expired delegations make room for new ones.
+                delegates.retain(|delegate| delegate.who != who && delegate.is_active(&now));
+                delegates
+                    .try_push(Delegate { who: who.clone(), permissions, expires_at })
+                    .map_err(|_| Error::<T>::TooManyDelegates)?;
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::DelegateAdded(claim, who, permissions, expires_at));
+
+            Ok(().into())
+        }
+
+        /// Withdraw the authorization of `who` over a claim.
+        ///
+        /// Either the owner of the claim or the delegate itself can call this.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::remove_delegate(T::MaxDelegates::get()))]
+        pub fn remove_delegate(
+            origin: OriginFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+
+            ensure!(info.owner == sender || who == sender, Error::<T>::NotClaimOwner);
+
+            Delegates::<T>::try_mutate_exists(&claim, |delegates| -> DispatchResult {
+                let list = delegates.as_mut().ok_or(Error::<T>::NotDelegate)?;
+                let index = list
+                    .iter()
+                    .position(|delegate| delegate.who == who)
+                    .ok_or(Error::<T>::NotDelegate)?;
+                list.remove(index);
+                if list.is_empty() {
+                    *delegates = None;
+                }
+
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::DelegateRemoved(claim, who));
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The deposit to hold for a claim taking up `bytes`: a fixed base plus a per-byte part.
+        pub fn deposit_for(bytes: u32) -> BalanceOf<T> {
+            T::DepositPerByte::get()
+                .saturating_mul(bytes.into())
+                .saturating_add(T::DepositBase::get())
+        }
+
+        fn do_create_claim(
+            owner: T::AccountId,
+            depositor: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            kind: ClaimKind,
+            metadata: Option<ClaimMetadata<T>>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            // A claim past its expiry is gone, whether or not `on_idle` has swept it yet.
+            if let Some(info) = Proofs::<T>::get(&claim) {
+                ensure!(info.is_expired(&now), Error::<T>::ProofAlreadyExist);
+                if let Some(block) = info.expires_at {
+                    Self::expire_claim(block, claim.clone());
+                }
+            }
+            if let Some(tombstone) = Revocations::<T>::get(&claim) {
+                ensure!(
+                    tombstone.reason == CHALLENGE_UPHELD_REASON && tombstone.revoker == owner,
+                    Error::<T>::ClaimRevoked
+                );
+                Revocations::<T>::remove(&claim);
+            }
+
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at > now, Error::<T>::ExpiryInPast);
+            }
+
+            Self::index_claim(&owner, &claim)?;
+
+            let bytes = claim.len() as u32 + metadata.as_ref().map_or(0, |m| m.byte_len());
+            let deposit = Self::deposit_for(bytes);
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &depositor, deposit)?;
+
+            if let Some(expires_at) = expires_at {
+                ClaimExpiries::<T>::insert(expires_at, &claim, ());
+            }
+            if let Some(metadata) = metadata {
+                Metadata::<T>::insert(&claim, metadata);
+            }
+
+            Proofs::<T>::insert(
+                &claim, //key
+                ClaimInfo {
+                    owner: owner.clone(),
+                    block_number: now,
+                    created_at: now,
+                    timestamp: T::Time::now(),
+                    depositor,
+                    deposit,
+                    expires_at,
+                    kind,
+                }, //value
+            );
+
+            Self::deposit_event(Event::ClaimCreated(owner, claim));
+
+            Ok(())
+        }
+
+        /// Offer a claim to `dest` on behalf of `who`, its owner or a delegate allowed to
+        /// transfer it.
+        fn do_offer_claim(
+            who: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let info = Self::live_claim(&claim)?;
+
+            Self::ensure_can_act(&claim, &info, &who, DelegatePermissions::TRANSFER)?;
+            ensure!(dest != info.owner, Error::<T>::OfferToSelf);
+            ensure!(!CoOwners::<T>::contains_key(&claim), Error::<T>::ApprovalRequired);
+            ensure!(!Disputes::<T>::contains_key(&claim), Error::<T>::ClaimDisputed);
+
+            PendingOffers::<T>::insert(&claim, &dest);
+
+            Self::deposit_event(Event::ClaimOffered(info.owner, dest, claim));
+
+            Ok(())
+        }
+
+        /// Revoke a claim held alone by its owner on behalf of `who`, the owner or a delegate
+        /// allowed to revoke it, leaving a tombstone if there is a `reason`.
+        fn do_revoke_owned_claim(
+            who: T::AccountId,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            reason: Option<u16>,
+        ) -> DispatchResult {
+            let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+
+            Self::ensure_can_act(&claim, &info, &who, DelegatePermissions::REVOKE)?;
+            ensure!(!CoOwners::<T>::contains_key(&claim), Error::<T>::ApprovalRequired);
+
+            Self::do_revoke_claim(claim, info, reason.map(|reason| (who, reason)))
+        }
+
+        /// Check that `who` owns the claim, or is an active delegate with `permission` over it.
+        fn ensure_can_act(
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+            info: &ClaimInfoOf<T>,
+            who: &T::AccountId,
+            permission: DelegatePermissions,
+        ) -> DispatchResult {
+            if info.owner == *who {
+                return Ok(());
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                Delegates::<T>::get(claim).iter().any(|delegate| {
+                    delegate.who == *who
+                        && delegate.permissions.contains(permission)
+                        && delegate.is_active(&now)
+                }),
+                Error::<T>::NotClaimOwner
+            );
+
+            Ok(())
+        }
+
+        /// Remove `claim`, releasing its deposit.
+        ///
+        /// With a `(revoker, reason)`, a tombstone is left in `Revocations`.
+        fn do_revoke_claim(
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            info: ClaimInfoOf<T>,
+            revocation: Option<(T::AccountId, u16)>,
+        ) -> DispatchResult {
+            ensure!(!Disputes::<T>::contains_key(&claim), Error::<T>::ClaimDisputed);
+            ensure!(
+                revocation.as_ref().map_or(true, |(_, reason)| *reason != CHALLENGE_UPHELD_REASON),
+                Error::<T>::ReservedReason
+            );
+
+            T::Currency::release(
+                &HoldReason::ClaimDeposit.into(),
+                &info.depositor,
+                info.deposit,
+                Precision::BestEffort,
+            )?;
+            Proofs::<T>::remove(&claim);
+            Self::remove_claim_data(&claim);
+            Self::unindex_claim(&info.owner, &claim);
+            if let Some(expires_at) = info.expires_at {
+                ClaimExpiries::<T>::remove(expires_at, &claim);
+            }
+
+            Self::deposit_event(Event::ClaimRevoked(info.owner, claim.clone()));
+
+            if let Some((revoker, reason)) = revocation {
+                Revocations::<T>::insert(
+                    &claim,
+                    Tombstone {
+                        revoker: revoker.clone(),
+                        reason,
+                        block_number: frame_system::Pallet::<T>::block_number(),
+                        created_at: info.created_at,
+                    },
+                );
+                Self::deposit_event(Event::RevocationRecorded(revoker, claim, reason));
+            }
+
+            Ok(())
+        }
+
+        /// Remove what is stored alongside a claim that goes away.
+        fn remove_claim_data(claim: &BoundedVec<u8, T::MaxClaimLength>) {
+            Metadata::<T>::remove(claim);
+            PendingOffers::<T>::remove(claim);
+            CoOwners::<T>::remove(claim);
+            Approvals::<T>::remove(claim);
+            Provenance::<T>::remove(claim);
+            Delegates::<T>::remove(claim);
+        }
+
+        /// Hand `claim` over to `dest`, who takes over its deposit.
+        fn do_transfer_claim(
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+            info: ClaimInfoOf<T>,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            ensure!(!Disputes::<T>::contains_key(&claim), Error::<T>::ClaimDisputed);
+
+            // The new owner pays for the storage of the claim from now on, and the old depositor
+            // gets their own funds back.
+            T::Currency::hold(&HoldReason::ClaimDeposit.into(), &dest, info.deposit)?;
+            T::Currency::release(
+                &HoldReason::ClaimDeposit.into(),
+                &info.depositor,
+                info.deposit,
+                Precision::BestEffort,
+            )?;
+
+            let from = info.owner.clone();
+            let now = frame_system::Pallet::<T>::block_number();
+            // The new owner holds the claim alone, and picks their own delegates.
+            CoOwners::<T>::remove(&claim);
+            Approvals::<T>::remove(&claim);
+            Delegates::<T>::remove(&claim);
+            Self::unindex_claim(&from, &claim);
+            Self::index_claim(&dest, &claim)?;
+            Provenance::<T>::mutate(&claim, |records| {
+                if records.is_full() {
+                    records.remove(0);
+                }
+                let _ = records.try_push(ProvenanceRecord {
+                    owner: from.clone(),
+                    block_number: now,
+                    timestamp: T::Time::now(),
+                });
+            });
+            Proofs::<T>::insert(
+                &claim,
+                ClaimInfo {
+                    owner: dest.clone(),
+                    block_number: now,
+                    timestamp: T::Time::now(),
+                    depositor: dest.clone(),
+                    ..info
+                },
+            );
+
+            Self::deposit_event(Event::ClaimTransferred { from, to: dest, claim });
+
+            Ok(())
+        }
+
+        /// Check `leaf` at position `index` against the anchored Merkle root `root`.
+        pub fn do_verify_inclusion(
+            root: H256,
+            leaf: H256,
+            index: u32,
+            proof: &[H256],
+        ) -> DispatchResult {
+            let info = Proofs::<T>::get(Self::hash_claim(&root)?).ok_or(Error::<T>::ClaimNotExist)?;
+            let ClaimKind::MerkleRoot(algorithm) = info.kind else {
+                return Err(Error::<T>::NotMerkleRoot.into());
+            };
+
+            // Leaves beyond the width of a tree of this depth cannot be part of it.
+            ensure!(
+                index.checked_shr(proof.len() as u32).unwrap_or(0) == 0,
+                Error::<T>::InvalidProof
+            );
+            ensure!(
+                algorithm.merkle_root(leaf, index, proof) == root,
+                Error::<T>::InvalidProof
+            );
+
+            Ok(())
+        }
+
+        /// The claim stored under the bytes of `hash`.
+        fn hash_claim(hash: &H256) -> Result<BoundedVec<u8, T::MaxClaimLength>, Error<T>> {
+            BoundedVec::try_from(hash.as_bytes().to_vec()).map_err(|_| Error::<T>::ClaimTooLong)
+        }
+
+        /// The claim, failing if it does not exist or has expired without being swept yet.
+        fn live_claim(
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> Result<ClaimInfoOf<T>, DispatchError> {
+            let info = Proofs::<T>::get(claim).ok_or(Error::<T>::ClaimNotExist)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(!info.is_expired(&now), Error::<T>::ClaimExpired);
+
+            Ok(info)
+        }
+
+        /// The claim stored under the raw bytes `claim`, if any and it has not expired.
+        pub fn get_claim(claim: Vec<u8>) -> Option<ClaimInfoOf<T>> {
+            let claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim).ok()?;
+            let now = frame_system::Pallet::<T>::block_number();
+            Proofs::<T>::get(&claim).filter(|info| !info.is_expired(&now))
+        }
+
+        /// The claim stored under the raw bytes `claim` within `namespace`, if any.
+        pub fn get_namespaced_claim(namespace: Vec<u8>, claim: Vec<u8>) -> Option<ClaimInfoOf<T>> {
+            let namespace = BoundedVec::<u8, T::MaxNamespaceLength>::try_from(namespace).ok()?;
+            let claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim).ok()?;
+            NamespacedProofs::<T>::get(&namespace, &claim)
+        }
+
+        /// The tombstone of the claim stored under the raw bytes `claim`, if it was revoked with
+        /// a reason.
+        pub fn tombstone(claim: Vec<u8>) -> Option<TombstoneOf<T>> {
+            let claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim).ok()?;
+            Revocations::<T>::get(&claim)
+        }
+
+        /// Past owners of the claim stored under the raw bytes `claim`, oldest first.
+        pub fn provenance(claim: Vec<u8>) -> Vec<ProvenanceRecordOf<T>> {
+            BoundedVec::<u8, T::MaxClaimLength>::try_from(claim)
+                .map(|claim| Provenance::<T>::get(&claim).into_inner())
+                .unwrap_or_default()
+        }
+
+        /// Claims owned by `owner`, skipping the first `offset` and returning at most `limit`.
+        pub fn claims_of(owner: &T::AccountId, offset: u32, limit: u32) -> Vec<Vec<u8>> {
+            ClaimsByOwner::<T>::iter_key_prefix(owner)
+                .skip(offset as usize)
+                .take(limit.min(T::MaxClaimsPerOwner::get()) as usize)
+                .map(|claim| claim.into_inner())
+                .collect()
+        }
+
+        fn index_claim(
+            owner: &T::AccountId,
+            claim: &BoundedVec<u8, T::MaxClaimLength>,
+        ) -> DispatchResult {
+            OwnedClaimCount::<T>::try_mutate(owner, |count| -> DispatchResult {
+                ensure!(*count < T::MaxClaimsPerOwner::get(), Error::<T>::TooManyClaims);
+                *count += 1;
+                Ok(())
+            })?;
+            ClaimsByOwner::<T>::insert(owner, claim, ());
+
+            Ok(())
+        }
+
+        fn unindex_claim(owner: &T::AccountId, claim: &BoundedVec<u8, T::MaxClaimLength>) {
+            ClaimsByOwner::<T>::remove(owner, claim);
+            OwnedClaimCount::<T>::mutate_exists(owner, |count| {
+                *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+            });
+        }
+
+        /// Remove expired claims, oldest first, for as long as `limit` allows.
+        pub(crate) fn sweep_expired_claims(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(limit);
+
+            // Reading and writing back the cursor.
+            if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+                return Weight::zero();
+            }
+
+            let mut cursor = ExpiryCursor::<T>::get();
+            while cursor <= now {
+                // Looking up the next claim expiring at `cursor`.
+                if meter.try_consume(db.reads(1)).is_err() {
+                    break;
+                }
+                match ClaimExpiries::<T>::iter_key_prefix(cursor).next() {
+                    Some(claim) => {
+                        if meter
+                            .try_consume(T::WeightInfo::expire_claim(claim.len() as u32))
+                            .is_err()
+                        {
+                            break;
+                        }
+                        Self::expire_claim(cursor, claim);
+                    }
+                    None => cursor.saturating_inc(),
+                }
+            }
+            ExpiryCursor::<T>::put(cursor);
+
+            meter.consumed()
+        }
+
+        pub(crate) fn expire_claim(
+            block: BlockNumberFor<T>,
+            claim: BoundedVec<u8, T::MaxClaimLength>,
+        ) {
+            ClaimExpiries::<T>::remove(block, &claim);
+
+            if let Some(info) = Proofs::<T>::take(&claim) {
+                Self::remove_claim_data(&claim);
+                Self::unindex_claim(&info.owner, &claim);
+                let _ = T::Currency::release(
+                    &HoldReason::ClaimDeposit.into(),
+                    &info.depositor,
+                    info.deposit,
+                    Precision::BestEffort,
+                );
+
+                Self::deposit_event(Event::ClaimExpired(info.owner, claim.clone()));
+
+                if let Some(dispute) = Disputes::<T>::take(&claim) {
+                    let _ = T::Currency::release(
+                        &HoldReason::ChallengeBond.into(),
+                        &dispute.challenger,
+                        dispute.bond,
+                        Precision::BestEffort,
+                    );
+
+                    Self::deposit_event(Event::ChallengeDropped(dispute.challenger, claim));
+                }
+            }
+        }
+
+        /// Remove commitments whose reveal window has passed, for as long as `limit` allows.
+        pub(crate) fn sweep_expired_commitments(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut meter = WeightMeter::with_limit(limit);
+
+            // Reading and writing back the cursor.
+            if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+                return Weight::zero();
+            }
+
+            let mut cursor = CommitmentCursor::<T>::get();
+            while cursor <= now {
+                // Looking up the next commitment expiring at `cursor`.
+                if meter.try_consume(db.reads(1)).is_err() {
+                    break;
+                }
+                match CommitmentExpiries::<T>::iter_key_prefix(cursor).next() {
+                    Some(salted_hash) => {
+                        if meter.try_consume(T::WeightInfo::expire_commitment()).is_err() {
+                            break;
+                        }
+                        Self::expire_commitment(cursor, salted_hash);
+                    }
+                    None => cursor.saturating_inc(),
+                }
+            }
+            CommitmentCursor::<T>::put(cursor);
+
+            meter.consumed()
+        }
+
+        pub(crate) fn expire_commitment(block: BlockNumberFor<T>, salted_hash: T::Hash) {
+            CommitmentExpiries::<T>::remove(block, salted_hash);
+
+            if let Some(commitment) = Commitments::<T>::take(salted_hash) {
+                let _ = T::Currency::release(
+                    &HoldReason::CommitmentDeposit.into(),
+                    &commitment.who,
+                    commitment.deposit,
+                    Precision::BestEffort,
+                );
+
+                Self::deposit_event(Event::CommitmentExpired(commitment.who, salted_hash));
+            }
+        }
+    }
+
+    impl<T: Config> Call<T> {
+        /// Upper bound on the number of claims the call creates for its signer.
+        pub fn claims_created(&self) -> u32 {
+            match self {
+                Call::create_claim { .. }
+                | Call::create_claim_with_expiry { .. }
+                | Call::anchor_merkle_root { .. }
+                | Call::create_digest_claim { .. }
+                | Call::create_claim_for { .. }
+                | Call::reveal_claim { .. }
+                | Call::create_namespaced_claim { .. } => 1,
+                Call::create_claims { claims } => claims.len() as u32,
+                _ => 0,
+            }
+        }
     }
 }
@@ -0,0 +1,234 @@
+//! Storage migrations of the proof-of-existence pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+use frame_system::pallet_prelude::BlockNumberFor;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Moves claims from the original `(owner, block_number)` format to `ClaimInfo`.
+pub mod v1 {
+    use super::*;
+    use sp_runtime::traits::Zero;
+
+    /// A claim as stored before version 1: its owner and the block it was created in.
+    pub type OldClaimInfoOf<T> = (<T as frame_system::Config>::AccountId, BlockNumberFor<T>);
+
+    /// A claim as stored in version 1, before `created_at` was recorded.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ClaimInfo<AccountId, BlockNumber, Balance, Moment> {
+        pub owner: AccountId,
+        pub block_number: BlockNumber,
+        pub timestamp: Moment,
+        pub depositor: AccountId,
+        pub deposit: Balance,
+        pub expires_at: Option<BlockNumber>,
+        pub kind: ClaimKind,
+    }
+
+    pub type ClaimInfoOf<T> = ClaimInfo<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        BalanceOf<T>,
+        MomentOf<T>,
+    >;
+
+    /// `Proofs` as of version 1.
+    #[frame_support::storage_alias]
+    pub type Proofs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxClaimLength>,
+        ClaimInfoOf<T>,
+    >;
+
+    /// Migrates `Proofs` to version 1 and indexes the claims by owner.
+    ///
+    /// Claims were free before version 1, so they keep a zero deposit, never expire and are
+    /// documents. The time of past blocks is not known on-chain, so they get a zero timestamp.
+    /// `Commitments` did not exist yet and is left alone, and the expiry sweeps start at the
+    /// block of the upgrade.
+    ///
+    /// Every claim is migrated in the block of the upgrade. That is fine for the handful of
+    /// claims on the chains running version 0, but a larger map would need a multi-block
+    /// migration.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+            Proofs::<T>::translate::<OldClaimInfoOf<T>, _>(|claim, (owner, block_number)| {
+                translated += 1;
+                // Accounts may end up with more than `MaxClaimsPerOwner` claims, and cannot
+                // create new ones until they are back under it.
+                ClaimsByOwner::<T>::insert(&owner, &claim, ());
+                OwnedClaimCount::<T>::mutate(&owner, |count| *count = count.saturating_add(1));
+                Some(ClaimInfo {
+                    owner: owner.clone(),
+                    block_number,
+                    timestamp: Default::default(),
+                    depositor: owner,
+                    deposit: Zero::zero(),
+                    expires_at: None,
+                    kind: ClaimKind::Document,
+                })
+            });
+            // Nothing expired before version 1, so the sweeps start here instead of walking
+            // every past block.
+            let now = frame_system::Pallet::<T>::block_number();
+            ExpiryCursor::<T>::put(now);
+            CommitmentCursor::<T>::put(now);
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(2 * translated + 2, 3 * translated + 3)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 0,
+                "pallet-poe: MigrateToV1 expects storage version 0"
+            );
+
+            let claims = Proofs::<T>::iter_keys().count() as u32;
+
+            Ok(claims.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let claims: u32 = Decode::decode(&mut &state[..])
+                .map_err(|_| "pallet-poe: cannot decode the pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "pallet-poe: storage version was not bumped to 1"
+            );
+            // Entries that fail to decode as the new type are skipped when iterating.
+            ensure!(
+                Proofs::<T>::iter_values().count() as u32 == claims,
+                "pallet-poe: not every claim was migrated"
+            );
+            ensure!(
+                Proofs::<T>::iter()
+                    .all(|(claim, info)| ClaimsByOwner::<T>::contains_key(&info.owner, &claim)),
+                "pallet-poe: not every claim is indexed by its owner"
+            );
+            ensure!(
+                OwnedClaimCount::<T>::iter_values().sum::<u32>() == claims,
+                "pallet-poe: claim counts do not match the claims"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Records the block each claim was created in, which transfers no longer overwrite.
+pub mod v2 {
+    use super::*;
+    use sp_runtime::traits::Zero;
+
+    /// A tombstone as stored before version 2.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Tombstone<AccountId, BlockNumber> {
+        pub revoker: AccountId,
+        pub reason: u16,
+        pub block_number: BlockNumber,
+    }
+
+    pub type TombstoneOf<T> = Tombstone<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// Migrates `Proofs`, `NamespacedProofs` and `Revocations` to version 2.
+    ///
+    /// The block a claim was created in is lost once it has changed hands, so claims are taken
+    /// to be created in their `block_number`. Revoked claims get a zero `created_at`, which keeps
+    /// their tombstones from being cleared by any reveal.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let upgrade = |old: v1::ClaimInfoOf<T>| ClaimInfo {
+                owner: old.owner,
+                block_number: old.block_number,
+                created_at: old.block_number,
+                timestamp: old.timestamp,
+                depositor: old.depositor,
+                deposit: old.deposit,
+                expires_at: old.expires_at,
+                kind: old.kind,
+            };
+
+            let mut translated = 0u64;
+            Proofs::<T>::translate::<v1::ClaimInfoOf<T>, _>(|_, old| {
+                translated += 1;
+                Some(upgrade(old))
+            });
+            NamespacedProofs::<T>::translate::<v1::ClaimInfoOf<T>, _>(|_, _, old| {
+                translated += 1;
+                Some(upgrade(old))
+            });
+            Revocations::<T>::translate::<TombstoneOf<T>, _>(|_, old| {
+                translated += 1;
+                Some(crate::Tombstone {
+                    revoker: old.revoker,
+                    reason: old.reason,
+                    block_number: old.block_number,
+                    created_at: Zero::zero(),
+                })
+            });
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "pallet-poe: MigrateToV2 expects storage version 1"
+            );
+
+            let entries = (
+                Proofs::<T>::iter_keys().count() as u32,
+                NamespacedProofs::<T>::iter_keys().count() as u32,
+                Revocations::<T>::iter_keys().count() as u32,
+            );
+
+            Ok(entries.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (claims, namespaced, tombstones): (u32, u32, u32) = Decode::decode(&mut &state[..])
+                .map_err(|_| "pallet-poe: cannot decode the pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "pallet-poe: storage version was not bumped to 2"
+            );
+            // Entries that fail to decode as the new type are skipped when iterating.
+            ensure!(
+                Proofs::<T>::iter_values().count() as u32 == claims
+                    && NamespacedProofs::<T>::iter_values().count() as u32 == namespaced,
+                "pallet-poe: not every claim was migrated"
+            );
+            ensure!(
+                Revocations::<T>::iter_values().count() as u32 == tombstones,
+                "pallet-poe: not every tombstone was migrated"
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -3,8 +3,10 @@ use frame_support::{
     derive_impl,
     traits::{ConstU16, ConstU32, ConstU64},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
@@ -16,6 +18,8 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
         PoeModule: pallet_poe,
     }
 );
@@ -38,7 +42,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -47,16 +51,88 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
 impl pallet_poe::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type MaxClaimLength = ConstU32<16>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type DepositBase = ConstU64<2>;
+    type DepositPerByte = ConstU64<1>;
+    type MaxClaimLength = ConstU32<32>;
+    type MaxClaimsPerOwner = ConstU32<4>;
+    type MaxProofDepth = ConstU32<8>;
+    type MaxMimeTypeLength = ConstU32<16>;
+    type MaxUriLength = ConstU32<32>;
+    type RevealWindow = ConstU64<5>;
+    type MaxCoOwners = ConstU32<3>;
+    type ApprovalTimeout = ConstU64<5>;
+    type MaxProvenance = ConstU32<2>;
+    type ChallengeBond = ConstU64<10>;
+    type ArbitratorOrigin = EnsureRoot<u64>;
+    type NamespaceDeposit = ConstU64<20>;
+    type MaxNamespaceLength = ConstU32<8>;
+    type MaxNamespaceWriters = ConstU32<2>;
+    type MaxBatchSize = ConstU32<3>;
+    type MaxDelegates = ConstU32<2>;
+    type ClaimRateWindow = ConstU64<10>;
+    type MaxClaimsPerWindow = ConstU32<2>;
+    type Time = Timestamp;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestSignatures;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestSignatures;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<UintAuthorityId, TestSignature> for TestSignatures {
+    fn signer() -> UintAuthorityId {
+        UintAuthorityId(1)
+    }
+
+    fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+        TestSignature(signer.0, message.to_vec())
+    }
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100), (4, 3)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    // Events are not recorded in the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
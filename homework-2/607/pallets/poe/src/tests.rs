@@ -1,6 +1,26 @@
 use super::*;
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{claim_rate, migrations, mock::*, Error};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::InspectHold, ConstU32, GetStorageVersion, Hooks, OnRuntimeUpgrade,
+		StorageVersion,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+	DispatchError, DispatchResult, TokenError,
+};
+
+fn held(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &who)
+}
 
 #[test]
 fn create_claim_works() {
@@ -10,7 +30,39 @@ fn create_claim_works() {
 
 		assert_eq!(
 			Proofs::<Test>::get(&claim),
-			Some((1, frame_system::Pallet::<Test>::block_number()))
+			Some(ClaimInfo {
+				owner: 1,
+				block_number: frame_system::Pallet::<Test>::block_number(),
+				created_at: frame_system::Pallet::<Test>::block_number(),
+				timestamp: 0,
+				depositor: 1,
+				deposit: 4,
+				expires_at: None,
+				kind: ClaimKind::Document,
+			})
+		);
+	})
+}
+
+#[test]
+fn create_claim_holds_deposit_per_byte() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1, 2, 3]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+
+		// DepositBase of 2 plus 1 per byte.
+		assert_eq!(held(1), 6);
+		assert_eq!(Balances::free_balance(1), 94);
+	})
+}
+
+#[test]
+fn create_claim_failed_without_funds_for_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(4), claim.clone()),
+			TokenError::FundsUnavailable
 		);
 	})
 }
@@ -35,6 +87,20 @@ fn revoke_claim_works() {
 		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
 
 		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim), None);
+	})
+}
+
+#[test]
+fn revoke_claim_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_eq!(held(1), 4);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_eq!(held(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
 	})
 }
 
@@ -70,6 +136,18 @@ fn transfer_claim() {
 	})
 }
 
+#[test]
+fn transfer_claim_only_offers_the_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+		assert_eq!(PendingOffers::<Test>::get(&claim), Some(2));
+	})
+}
+
 #[test]
 fn transfer_claim_when_claim_is_not_exist() {
 	new_test_ext().execute_with(|| {
@@ -93,3 +171,1823 @@ fn transfer_claim_with_wrong_owner() {
 	})
 
 }
+
+#[test]
+fn create_claim_with_expiry_works() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 5));
+
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().expires_at, Some(5));
+		assert!(ClaimExpiries::<Test>::contains_key(5, &claim));
+	})
+}
+
+#[test]
+fn create_claim_with_expiry_failed_when_expiry_in_past() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 1),
+			Error::<Test>::ExpiryInPast
+		);
+	})
+}
+
+#[test]
+fn expired_claims_are_swept_on_idle() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 3);
+
+		PoeModule::on_idle(2, Weight::MAX);
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		System::set_block_number(3);
+		PoeModule::on_idle(3, Weight::MAX);
+		assert_eq!(Proofs::<Test>::get(&claim), None);
+		assert!(!ClaimExpiries::<Test>::contains_key(3, &claim));
+		assert_eq!(held(1), 0);
+		assert_eq!(ExpiryCursor::<Test>::get(), 4);
+		System::assert_last_event(Event::ClaimExpired(1, claim).into());
+	})
+}
+
+#[test]
+fn expired_claims_are_kept_without_idle_weight() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 3);
+
+		System::set_block_number(3);
+		assert_eq!(PoeModule::on_idle(3, Weight::zero()), Weight::zero());
+		assert!(Proofs::<Test>::contains_key(&claim));
+	})
+}
+
+#[test]
+fn unswept_expired_claims_are_not_live() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 3);
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+
+		System::set_block_number(3);
+		PoeModule::on_idle(3, Weight::zero());
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		assert_eq!(PoeModule::get_claim(claim.to_vec()), None);
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 3),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::renew_claim(RuntimeOrigin::signed(1), claim.clone(), Some(10)),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(3), claim.clone()),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::add_delegate(
+				RuntimeOrigin::signed(1),
+				claim.clone(),
+				3,
+				DelegatePermissions::REVOKE,
+				None
+			),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::set_co_owners(
+				RuntimeOrigin::signed(1),
+				claim.clone(),
+				BoundedVec::try_from(vec![1, 2]).unwrap(),
+				1
+			),
+			Error::<Test>::ClaimExpired
+		);
+
+		// Creating the claim again expires the old one first.
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+		assert!(!ClaimExpiries::<Test>::contains_key(3, &claim));
+		assert!(!PendingOffers::<Test>::contains_key(&claim));
+		assert_eq!(PoeModule::claims_of(&1, 0, 10), Vec::<Vec<u8>>::new());
+		assert_eq!(held(1), 0);
+	})
+}
+
+#[test]
+fn renew_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 3);
+
+		assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(1), claim.clone(), Some(10)));
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().expires_at, Some(10));
+		assert!(!ClaimExpiries::<Test>::contains_key(3, &claim));
+		assert!(ClaimExpiries::<Test>::contains_key(10, &claim));
+
+		assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().expires_at, None);
+		assert!(!ClaimExpiries::<Test>::contains_key(10, &claim));
+	})
+}
+
+#[test]
+fn renew_claim_failed_with_wrong_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 3);
+		assert_noop!(
+			PoeModule::renew_claim(RuntimeOrigin::signed(2), claim.clone(), Some(10)),
+			Error::<Test>::NotClaimOwner
+		);
+	})
+}
+
+#[test]
+fn accept_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+		System::assert_last_event(Event::ClaimOffered(1, 2, claim.clone()).into());
+
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 2);
+		assert_eq!(PendingOffers::<Test>::get(&claim), None);
+		System::assert_last_event(Event::ClaimTransferred { from: 1, to: 2, claim }.into());
+	})
+}
+
+#[test]
+fn accept_claim_moves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+
+		// The old owner gets their deposit back and the new owner pays their own.
+		assert_eq!(held(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(held(2), 4);
+		assert_eq!(Balances::free_balance(2), 96);
+	})
+}
+
+#[test]
+fn accept_claim_failed_without_funds_for_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 4);
+
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(4), claim.clone()),
+			TokenError::FundsUnavailable
+		);
+	})
+}
+
+#[test]
+fn accept_claim_failed_when_not_offered_to_sender() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(3), claim.clone()),
+			Error::<Test>::NotOfferTarget
+		);
+	})
+}
+
+#[test]
+fn offer_claim_failed_to_self() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 1),
+			Error::<Test>::OfferToSelf
+		);
+	})
+}
+
+#[test]
+fn cancel_offer_works() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+
+		assert_noop!(
+			PoeModule::cancel_offer(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()));
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+	})
+}
+
+#[test]
+fn revoke_claim_drops_pending_offer() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_eq!(PendingOffers::<Test>::get(&claim), None);
+	})
+}
+
+#[test]
+fn claims_by_owner_follows_create_revoke_and_transfer() {
+	new_test_ext().execute_with(|| {
+		let claim: BoundedVec<u8, _> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let other: BoundedVec<u8, _> = BoundedVec::try_from(vec![2, 3]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), other.clone());
+		assert!(ClaimsByOwner::<Test>::contains_key(1, &claim));
+		assert_eq!(OwnedClaimCount::<Test>::get(1), 2);
+
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert!(!ClaimsByOwner::<Test>::contains_key(1, &claim));
+		assert!(ClaimsByOwner::<Test>::contains_key(2, &claim));
+		assert_eq!(OwnedClaimCount::<Test>::get(1), 1);
+		assert_eq!(OwnedClaimCount::<Test>::get(2), 1);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), other.clone()));
+		assert!(!ClaimsByOwner::<Test>::contains_key(1, &other));
+		assert!(!OwnedClaimCount::<Test>::contains_key(1));
+	})
+}
+
+#[test]
+fn create_claim_failed_when_owner_has_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		for i in 0..4u8 {
+			let claim = BoundedVec::try_from(vec![i]).unwrap();
+			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+		}
+		let claim = BoundedVec::try_from(vec![4]).unwrap();
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(1), claim),
+			Error::<Test>::TooManyClaims
+		);
+	})
+}
+
+#[test]
+fn claims_of_is_paginated() {
+	new_test_ext().execute_with(|| {
+		for i in 0..4u8 {
+			let claim = BoundedVec::try_from(vec![i]).unwrap();
+			let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim);
+		}
+
+		let first = PoeModule::claims_of(&1, 0, 3);
+		let rest = PoeModule::claims_of(&1, 3, 3);
+		assert_eq!(first.len(), 3);
+		assert_eq!(rest.len(), 1);
+
+		let mut all = [first, rest].concat();
+		all.sort();
+		assert_eq!(all, vec![vec![0], vec![1], vec![2], vec![3]]);
+		assert!(PoeModule::claims_of(&2, 0, 3).is_empty());
+	})
+}
+
+#[test]
+fn get_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		let info = PoeModule::get_claim(vec![0, 1]).unwrap();
+		assert_eq!((info.owner, info.block_number), (1, 1));
+		assert!(PoeModule::get_claim(vec![1, 0]).is_none());
+		// Longer than `MaxClaimLength`, so it cannot have been stored.
+		assert!(PoeModule::get_claim(vec![0; 33]).is_none());
+	})
+}
+
+/// Root and per-leaf proofs of a Merkle tree over four leaves.
+fn merkle_tree(algorithm: HashAlgorithm, leaves: [H256; 4]) -> (H256, Vec<Vec<H256>>) {
+	let node = |l: H256, r: H256| algorithm.hash_node(l, r);
+	let [a, b, c, d] = leaves.map(|leaf| algorithm.hash_leaf(leaf));
+	let left = node(a, b);
+	let right = node(c, d);
+	let proofs = vec![vec![b, right], vec![a, right], vec![d, left], vec![c, left]];
+	(node(left, right), proofs)
+}
+
+#[test]
+fn verify_inclusion_works() {
+	new_test_ext().execute_with(|| {
+		for algorithm in [HashAlgorithm::Blake2_256, HashAlgorithm::Keccak256] {
+			let leaves = [0u8, 1, 2, 3].map(|i| H256::repeat_byte(i));
+			let (root, proofs) = merkle_tree(algorithm, leaves);
+			assert_ok!(PoeModule::anchor_merkle_root(RuntimeOrigin::signed(1), root, algorithm));
+			assert_eq!(
+				Proofs::<Test>::get(BoundedVec::try_from(root.as_bytes().to_vec()).unwrap())
+					.map(|info| info.kind),
+				Some(ClaimKind::MerkleRoot(algorithm))
+			);
+
+			for (index, proof) in proofs.into_iter().enumerate() {
+				let proof = BoundedVec::try_from(proof).unwrap();
+				assert_ok!(PoeModule::verify_inclusion(
+					RuntimeOrigin::signed(2),
+					root,
+					leaves[index],
+					index as u32,
+					proof,
+				));
+				System::assert_last_event(Event::InclusionVerified(root, leaves[index]).into());
+			}
+		}
+	})
+}
+
+#[test]
+fn verify_inclusion_failed_with_wrong_proof() {
+	new_test_ext().execute_with(|| {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let leaves = [0u8, 1, 2, 3].map(|i| H256::repeat_byte(i));
+		let (root, proofs) = merkle_tree(algorithm, leaves);
+		let proof: BoundedVec<_, _> = BoundedVec::try_from(proofs[0].clone()).unwrap();
+
+		assert_noop!(
+			PoeModule::verify_inclusion(
+				RuntimeOrigin::signed(1),
+				root,
+				leaves[0],
+				0,
+				proof.clone()
+			),
+			Error::<Test>::ClaimNotExist
+		);
+
+		let _ = PoeModule::anchor_merkle_root(RuntimeOrigin::signed(1), root, algorithm);
+		// Wrong leaf, wrong position, an index out of range and a tree built with another hash.
+		for (leaf, index) in [(leaves[1], 0), (leaves[0], 1), (leaves[0], 4)] {
+			assert_noop!(
+				PoeModule::verify_inclusion(
+					RuntimeOrigin::signed(1),
+					root,
+					leaf,
+					index,
+					proof.clone()
+				),
+				Error::<Test>::InvalidProof
+			);
+		}
+		let (keccak_root, _) = merkle_tree(HashAlgorithm::Keccak256, leaves);
+		let _ = PoeModule::anchor_merkle_root(RuntimeOrigin::signed(1), keccak_root, algorithm);
+		assert_noop!(
+			PoeModule::verify_inclusion(
+				RuntimeOrigin::signed(1),
+				keccak_root,
+				leaves[0],
+				0,
+				proof
+			),
+			Error::<Test>::InvalidProof
+		);
+	})
+}
+
+#[test]
+fn verify_inclusion_rejects_inner_node_as_leaf() {
+	new_test_ext().execute_with(|| {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let leaves = [0u8, 1, 2, 3].map(|i| H256::repeat_byte(i));
+		let (root, proofs) = merkle_tree(algorithm, leaves);
+		let _ = PoeModule::anchor_merkle_root(RuntimeOrigin::signed(1), root, algorithm);
+
+		// The parent of the first two leaves, with the rest of their proof.
+		let pair =
+			algorithm.hash_node(algorithm.hash_leaf(leaves[0]), algorithm.hash_leaf(leaves[1]));
+		let proof: BoundedVec<_, _> = BoundedVec::try_from(proofs[0][1..].to_vec()).unwrap();
+		assert_noop!(
+			PoeModule::verify_inclusion(RuntimeOrigin::signed(1), root, pair, 0, proof),
+			Error::<Test>::InvalidProof
+		);
+	})
+}
+
+#[test]
+fn verify_inclusion_failed_for_document_claim() {
+	new_test_ext().execute_with(|| {
+		let root = H256::repeat_byte(7);
+		let claim = BoundedVec::try_from(root.as_bytes().to_vec()).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim);
+
+		assert_noop!(
+			PoeModule::verify_inclusion(
+				RuntimeOrigin::signed(1),
+				root,
+				root,
+				0,
+				BoundedVec::new()
+			),
+			Error::<Test>::NotMerkleRoot
+		);
+	})
+}
+
+#[test]
+fn create_digest_claim_works() {
+	new_test_ext().execute_with(|| {
+		let digest = H256(sp_io::hashing::sha2_256(b"document"));
+		let mime_type = BoundedVec::try_from(b"text/plain".to_vec()).unwrap();
+		let uri = BoundedVec::try_from(b"ipfs://doc".to_vec()).unwrap();
+		assert_ok!(PoeModule::create_digest_claim(
+			RuntimeOrigin::signed(1),
+			digest,
+			HashAlgorithm::Sha2_256,
+			Some(mime_type.clone()),
+			Some(uri.clone()),
+		));
+
+		let claim = BoundedVec::try_from(digest.as_bytes().to_vec()).unwrap();
+		assert_eq!(
+			Proofs::<Test>::get(&claim).map(|info| info.kind),
+			Some(ClaimKind::Digest(HashAlgorithm::Sha2_256))
+		);
+		assert_eq!(
+			Metadata::<Test>::get(&claim),
+			Some(ClaimMetadata { mime_type: Some(mime_type), uri: Some(uri) })
+		);
+		// DepositBase of 2 plus 1 per byte of digest, MIME type and URI.
+		assert_eq!(held(1), 2 + 32 + 10 + 10);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert!(!Metadata::<Test>::contains_key(&claim));
+		assert_eq!(held(1), 0);
+	})
+}
+
+#[test]
+fn create_digest_claim_without_metadata() {
+	new_test_ext().execute_with(|| {
+		let digest = H256(sp_io::hashing::blake2_256(b"document"));
+		assert_ok!(PoeModule::create_digest_claim(
+			RuntimeOrigin::signed(1),
+			digest,
+			HashAlgorithm::Blake2_256,
+			None,
+			None,
+		));
+
+		let claim = BoundedVec::try_from(digest.as_bytes().to_vec()).unwrap();
+		assert!(!Metadata::<Test>::contains_key(&claim));
+		assert_eq!(held(1), 34);
+
+		assert_noop!(
+			PoeModule::create_digest_claim(
+				RuntimeOrigin::signed(2),
+				digest,
+				HashAlgorithm::Keccak256,
+				None,
+				None,
+			),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+fn sign_claim_for(
+	owner: u64,
+	claim: &BoundedVec<u8, ConstU32<32>>,
+	nonce: u64,
+	deadline: u64,
+) -> TestSignature {
+	let payload = ClaimForPayload {
+		tag: CLAIM_FOR_TAG,
+		genesis_hash: System::block_hash(0),
+		owner,
+		claim: claim.clone(),
+		nonce,
+		deadline,
+	};
+	TestSignature(owner, payload.encode())
+}
+
+#[test]
+fn create_claim_for_works() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		// Account 5 holds no funds at all.
+		let signature = sign_claim_for(5, &claim, 0, 10);
+		assert_ok!(PoeModule::create_claim_for(
+			RuntimeOrigin::signed(2),
+			5,
+			claim.clone(),
+			signature,
+			10
+		));
+
+		let info = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!((info.owner, info.depositor), (5, 2));
+		assert_eq!(held(2), 4);
+		assert_eq!(Nonces::<Test>::get(5), 1);
+		assert!(ClaimsByOwner::<Test>::contains_key(5, &claim));
+
+		// The owner can revoke the claim, which returns the deposit to the relayer.
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(5), claim));
+		assert_eq!(held(2), 0);
+		assert_eq!(Balances::free_balance(2), 100);
+	})
+}
+
+#[test]
+fn create_claim_for_failed_when_signature_is_replayed() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let signature = sign_claim_for(5, &claim, 0, 10);
+		let _ = PoeModule::create_claim_for(
+			RuntimeOrigin::signed(2),
+			5,
+			claim.clone(),
+			signature.clone(),
+			10,
+		);
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(5), claim.clone()));
+
+		assert_noop!(
+			PoeModule::create_claim_for(RuntimeOrigin::signed(2), 5, claim, signature, 10),
+			Error::<Test>::BadSignature
+		);
+	})
+}
+
+#[test]
+fn create_claim_for_failed_with_bad_signature() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let other = BoundedVec::try_from(vec![1, 0]).unwrap();
+
+		// Signed by someone else, for another claim and for another deadline.
+		for (signature, deadline) in [
+			(sign_claim_for(6, &claim, 0, 10), 10),
+			(sign_claim_for(5, &other, 0, 10), 10),
+			(sign_claim_for(5, &claim, 0, 10), 11),
+		] {
+			assert_noop!(
+				PoeModule::create_claim_for(
+					RuntimeOrigin::signed(2),
+					5,
+					claim.clone(),
+					signature,
+					deadline
+				),
+				Error::<Test>::BadSignature
+			);
+		}
+	})
+}
+
+#[test]
+fn create_claim_for_failed_with_signature_for_another_chain() {
+	new_test_ext().execute_with(|| {
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let payload = ClaimForPayload {
+			tag: CLAIM_FOR_TAG,
+			genesis_hash: System::block_hash(0),
+			owner: 5u64,
+			claim: claim.clone(),
+			nonce: 0u64,
+			deadline: 10u64,
+		};
+
+		// Signed for a chain with another genesis block, and without the pallet's tag.
+		let other_chain = ClaimForPayload { genesis_hash: H256::repeat_byte(1), ..payload.clone() };
+		let untagged = ClaimForPayload { tag: *b"poe/other_tag", ..payload };
+		for payload in [other_chain, untagged] {
+			assert_noop!(
+				PoeModule::create_claim_for(
+					RuntimeOrigin::signed(2),
+					5,
+					claim.clone(),
+					TestSignature(5, payload.encode()),
+					10
+				),
+				Error::<Test>::BadSignature
+			);
+		}
+	})
+}
+
+#[test]
+fn create_claim_for_failed_after_deadline() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let signature = sign_claim_for(5, &claim, 0, 1);
+		System::set_block_number(2);
+
+		assert_noop!(
+			PoeModule::create_claim_for(RuntimeOrigin::signed(2), 5, claim, signature, 1),
+			Error::<Test>::DeadlineExpired
+		);
+	})
+}
+
+fn commitment_deposit(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::CommitmentDeposit.into(), &who)
+}
+
+#[test]
+fn reveal_claim_keeps_commit_block() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let salted_hash = BlakeTwo256::hash_of(&(&claim, &salt));
+
+		assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(1), salted_hash));
+		assert_eq!(commitment_deposit(1), 2);
+		assert!(!Proofs::<Test>::contains_key(&claim));
+
+		System::set_block_number(4);
+		assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt));
+		System::assert_last_event(Event::ClaimRevealed(1, claim.clone(), 1).into());
+
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.block_number), Some(1));
+		assert!(!Commitments::<Test>::contains_key(salted_hash));
+		assert!(!CommitmentExpiries::<Test>::contains_key(6, salted_hash));
+		assert_eq!(commitment_deposit(1), 0);
+		assert_eq!(held(1), 4);
+	})
+}
+
+#[test]
+fn reveal_claim_wins_over_front_runner() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let other = BoundedVec::try_from(vec![1, 0]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let _ = PoeModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			BlakeTwo256::hash_of(&(&claim, &salt)),
+		);
+		let _ = PoeModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			BlakeTwo256::hash_of(&(&other, &salt)),
+		);
+
+		// Account 2 copies the first claim out of the pool and gets it in first, and account 3
+		// challenges it. The second claim is copied and then revoked with a reason.
+		System::set_block_number(2);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(3), claim.clone()));
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), other.clone()));
+		assert_ok!(PoeModule::revoke_claim_with_reason(RuntimeOrigin::signed(2), other.clone(), 1));
+
+		assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt));
+		assert_eq!(
+			Proofs::<Test>::get(&claim).map(|info| (info.owner, info.block_number)),
+			Some((1, 1))
+		);
+		assert!(!Disputes::<Test>::contains_key(&claim));
+		assert!(!ClaimsByOwner::<Test>::contains_key(2, &claim));
+		assert_eq!(held(2), 0);
+		assert_eq!(bond(3), 0);
+
+		assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), other.clone(), salt));
+		assert!(!Revocations::<Test>::contains_key(&other));
+		assert_eq!(held(1), 8);
+	})
+}
+
+#[test]
+fn reveal_claim_failed_for_claim_older_than_commitment() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone());
+
+		System::set_block_number(2);
+		let _ = PoeModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			BlakeTwo256::hash_of(&(&claim, &salt)),
+		);
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+#[test]
+fn reveal_claim_keeps_older_claim_that_changed_hands() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone());
+
+		System::set_block_number(2);
+		let _ = PoeModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			BlakeTwo256::hash_of(&(&claim, &salt)),
+		);
+
+		System::set_block_number(3);
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(2), claim.clone(), 3));
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(3), claim.clone()));
+		assert_eq!(
+			Proofs::<Test>::get(&claim).map(|info| (info.block_number, info.created_at)),
+			Some((3, 1))
+		);
+
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+#[test]
+fn reveal_claim_keeps_tombstone_of_older_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone());
+
+		System::set_block_number(2);
+		let _ = PoeModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			BlakeTwo256::hash_of(&(&claim, &salt)),
+		);
+
+		// The owner revokes their claim after the commitment, which does not make it newer.
+		System::set_block_number(3);
+		assert_ok!(PoeModule::revoke_claim_with_reason(RuntimeOrigin::signed(2), claim.clone(), 7));
+
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt),
+			Error::<Test>::ClaimRevoked
+		);
+	})
+}
+
+#[test]
+fn reveal_claim_failed_with_wrong_salt_or_sender() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let _ = PoeModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			BlakeTwo256::hash_of(&(&claim, &salt)),
+		);
+
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), H256::zero()),
+			Error::<Test>::CommitmentNotExist
+		);
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(2), claim, salt),
+			Error::<Test>::NotCommitter
+		);
+	})
+}
+
+#[test]
+fn commit_claim_failed_when_commitment_exist() {
+	new_test_ext().execute_with(|| {
+		let salted_hash = H256::repeat_byte(9);
+		let _ = PoeModule::commit_claim(RuntimeOrigin::signed(1), salted_hash);
+
+		assert_noop!(
+			PoeModule::commit_claim(RuntimeOrigin::signed(2), salted_hash),
+			Error::<Test>::CommitmentAlreadyExist
+		);
+	})
+}
+
+#[test]
+fn expired_commitments_are_swept_on_idle() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		let salted_hash = BlakeTwo256::hash_of(&(&claim, &salt));
+		let _ = PoeModule::commit_claim(RuntimeOrigin::signed(1), salted_hash);
+
+		// The reveal window of 5 blocks ends at block 6.
+		System::set_block_number(6);
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim, salt),
+			Error::<Test>::CommitmentExpired
+		);
+
+		PoeModule::on_idle(6, Weight::MAX);
+		assert!(!Commitments::<Test>::contains_key(salted_hash));
+		assert_eq!(commitment_deposit(1), 0);
+		assert_eq!(CommitmentCursor::<Test>::get(), 7);
+		System::assert_last_event(Event::CommitmentExpired(1, salted_hash).into());
+	})
+}
+
+fn co_owned_claim(threshold: u32) -> BoundedVec<u8, ConstU32<32>> {
+	let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+	let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+	assert_ok!(PoeModule::set_co_owners(
+		RuntimeOrigin::signed(1),
+		claim.clone(),
+		BoundedVec::try_from(vec![1, 2, 3]).unwrap(),
+		threshold,
+	));
+	claim
+}
+
+#[test]
+fn set_co_owners_failed_with_invalid_co_owners() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		// Owner missing, duplicates, and thresholds of zero and above the number of co-owners.
+		for (owners, threshold) in
+			[(vec![2, 3], 1), (vec![1, 2, 2], 2), (vec![1, 2], 0), (vec![1, 2], 3)]
+		{
+			assert_noop!(
+				PoeModule::set_co_owners(
+					RuntimeOrigin::signed(1),
+					claim.clone(),
+					BoundedVec::try_from(owners).unwrap(),
+					threshold,
+				),
+				Error::<Test>::InvalidCoOwners
+			);
+		}
+		assert_noop!(
+			PoeModule::set_co_owners(
+				RuntimeOrigin::signed(2),
+				claim,
+				BoundedVec::try_from(vec![1, 2]).unwrap(),
+				1,
+			),
+			Error::<Test>::NotClaimOwner
+		);
+	})
+}
+
+#[test]
+fn set_co_owners_failed_with_pending_offer() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let owners: BoundedVec<_, _> = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		// An offer to the owner's other account, to be accepted once the claim is co-owned.
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 4);
+
+		assert_noop!(
+			PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), owners.clone(), 2),
+			Error::<Test>::OfferPending
+		);
+
+		assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::set_co_owners(RuntimeOrigin::signed(1), claim.clone(), owners, 2));
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(4), claim),
+			Error::<Test>::OfferNotExist
+		);
+	})
+}
+
+#[test]
+fn co_owned_claim_needs_approvals_to_revoke() {
+	new_test_ext().execute_with(|| {
+		let claim = co_owned_claim(2);
+
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ApprovalRequired
+		);
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 4),
+			Error::<Test>::ApprovalRequired
+		);
+
+		assert_ok!(PoeModule::approve_action(
+			RuntimeOrigin::signed(2),
+			claim.clone(),
+			ClaimAction::Revoke
+		));
+		assert_noop!(
+			PoeModule::approve_action(RuntimeOrigin::signed(2), claim.clone(), ClaimAction::Revoke),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			PoeModule::approve_action(RuntimeOrigin::signed(4), claim.clone(), ClaimAction::Revoke),
+			Error::<Test>::NotCoOwner
+		);
+		assert_noop!(
+			PoeModule::approve_action(
+				RuntimeOrigin::signed(3),
+				claim.clone(),
+				ClaimAction::Transfer(4)
+			),
+			Error::<Test>::OtherActionPending
+		);
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		assert_ok!(PoeModule::approve_action(
+			RuntimeOrigin::signed(3),
+			claim.clone(),
+			ClaimAction::Revoke
+		));
+		System::assert_last_event(Event::ClaimRevoked(1, claim.clone()).into());
+		assert!(!Proofs::<Test>::contains_key(&claim));
+		assert!(!CoOwners::<Test>::contains_key(&claim));
+		assert!(!Approvals::<Test>::contains_key(&claim));
+		assert_eq!(held(1), 0);
+	})
+}
+
+#[test]
+fn co_owned_claim_transfer_is_offered_once_approved() {
+	new_test_ext().execute_with(|| {
+		let claim = co_owned_claim(2);
+
+		for who in [1, 3] {
+			assert_ok!(PoeModule::approve_action(
+				RuntimeOrigin::signed(who),
+				claim.clone(),
+				ClaimAction::Transfer(4)
+			));
+		}
+		assert_eq!(PendingOffers::<Test>::get(&claim), Some(4));
+
+		let _ = Balances::transfer_allow_death(RuntimeOrigin::signed(2), 4, 10);
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(4), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(4));
+		// The new owner holds the claim alone.
+		assert!(!CoOwners::<Test>::contains_key(&claim));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(4), claim));
+	})
+}
+
+#[test]
+fn approvals_expire() {
+	new_test_ext().execute_with(|| {
+		let claim = co_owned_claim(2);
+		let _ = PoeModule::approve_action(RuntimeOrigin::signed(2), claim.clone(), ClaimAction::Revoke);
+
+		// The approval timeout of 5 blocks ends at block 6, after which another action can start.
+		System::set_block_number(6);
+		assert_ok!(PoeModule::approve_action(
+			RuntimeOrigin::signed(3),
+			claim.clone(),
+			ClaimAction::Transfer(4)
+		));
+		assert_eq!(
+			Approvals::<Test>::get(&claim).map(|approval| approval.approvers.into_inner()),
+			Some(vec![3])
+		);
+		assert!(Proofs::<Test>::contains_key(&claim));
+	})
+}
+
+#[test]
+fn transfers_are_recorded_in_provenance() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		// Hand the claim around 1 -> 2 -> 3 -> 1, one block and second apart.
+		for (block, from, to) in [(2, 1, 2), (3, 2, 3), (4, 3, 1)] {
+			System::set_block_number(block);
+			Timestamp::set_timestamp(block * 1_000);
+			let _ = PoeModule::offer_claim(RuntimeOrigin::signed(from), claim.clone(), to);
+			assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(to), claim.clone()));
+		}
+
+		// Only the latest two records are kept.
+		assert_eq!(
+			PoeModule::provenance(claim.to_vec()),
+			vec![
+				ProvenanceRecord { owner: 2, block_number: 3, timestamp: 3_000 },
+				ProvenanceRecord { owner: 3, block_number: 4, timestamp: 4_000 },
+			]
+		);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert!(!Provenance::<Test>::contains_key(&claim));
+	})
+}
+
+#[test]
+fn revoke_claim_with_reason_leaves_tombstone() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::revoke_claim_with_reason(RuntimeOrigin::signed(1), claim.clone(), 7));
+		System::assert_last_event(Event::RevocationRecorded(1, claim.clone(), 7).into());
+
+		assert!(PoeModule::get_claim(claim.to_vec()).is_none());
+		assert_eq!(
+			PoeModule::tombstone(claim.to_vec()),
+			Some(Tombstone { revoker: 1, reason: 7, block_number: 2, created_at: 1 })
+		);
+		assert_eq!(held(1), 0);
+
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::ClaimRevoked
+		);
+	})
+}
+
+#[test]
+fn revoke_claim_with_reason_refuses_challenge_reason() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		assert_noop!(
+			PoeModule::revoke_claim_with_reason(
+				RuntimeOrigin::signed(1),
+				claim,
+				CHALLENGE_UPHELD_REASON
+			),
+			Error::<Test>::ReservedReason
+		);
+	})
+}
+
+#[test]
+fn plain_revoke_leaves_no_tombstone() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		assert!(PoeModule::tombstone(claim.to_vec()).is_none());
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim));
+	})
+}
+
+#[test]
+fn co_owned_claim_revoked_with_reason_by_approvals() {
+	new_test_ext().execute_with(|| {
+		let claim = co_owned_claim(2);
+
+		assert_noop!(
+			PoeModule::revoke_claim_with_reason(RuntimeOrigin::signed(1), claim.clone(), 7),
+			Error::<Test>::ApprovalRequired
+		);
+		for who in [2, 3] {
+			assert_ok!(PoeModule::approve_action(
+				RuntimeOrigin::signed(who),
+				claim.clone(),
+				ClaimAction::RevokeWithReason(7)
+			));
+		}
+
+		// The co-owner whose approval carried the action is the revoker.
+		assert_eq!(
+			PoeModule::tombstone(claim.to_vec()),
+			Some(Tombstone { revoker: 3, reason: 7, block_number: 1, created_at: 1 })
+		);
+	})
+}
+
+fn bond(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &who)
+}
+
+#[test]
+fn challenge_claim_holds_bond_and_freezes_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+
+		assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()));
+		System::assert_last_event(Event::ClaimChallenged(2, claim.clone(), 10).into());
+		assert_eq!(bond(2), 10);
+		assert_eq!(
+			Disputes::<Test>::get(&claim),
+			Some(Dispute { challenger: 2, bond: 10, block_number: 1 })
+		);
+
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ClaimDisputed
+		);
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 3),
+			Error::<Test>::ClaimDisputed
+		);
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(3), claim),
+			Error::<Test>::ClaimDisputed
+		);
+	})
+}
+
+#[test]
+fn challenge_claim_failed() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::ClaimNotExist
+		);
+
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ChallengeOwnClaim
+		);
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(4), claim.clone()),
+			TokenError::FundsUnavailable
+		);
+		assert_noop!(
+			PoeModule::resolve_dispute(RuntimeOrigin::root(), claim.clone(), Verdict::Rejected),
+			Error::<Test>::DisputeNotExist
+		);
+
+		let _ = PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone());
+		assert_noop!(
+			PoeModule::resolve_dispute(RuntimeOrigin::signed(1), claim, Verdict::Rejected),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn rejected_challenge_slashes_bond() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone());
+
+		assert_ok!(PoeModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			claim.clone(),
+			Verdict::Rejected
+		));
+		System::assert_last_event(Event::ChallengeRejected(2, claim.clone(), 10).into());
+
+		assert_eq!(bond(2), 0);
+		assert_eq!(Balances::free_balance(2), 90);
+		assert!(Disputes::<Test>::get(&claim).is_none());
+		// The claim stands and can be transferred again.
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim, 3));
+	})
+}
+
+#[test]
+fn upheld_challenge_revokes_claim_and_pays_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone());
+
+		assert_ok!(PoeModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			claim.clone(),
+			Verdict::Upheld
+		));
+		System::assert_has_event(Event::ClaimRevoked(1, claim.clone()).into());
+		System::assert_has_event(
+			Event::RevocationRecorded(2, claim.clone(), CHALLENGE_UPHELD_REASON).into(),
+		);
+		System::assert_last_event(Event::ChallengeUpheld(2, claim.clone(), 4).into());
+
+		assert!(PoeModule::get_claim(claim.to_vec()).is_none());
+		assert!(PoeModule::claims_of(&1, 0, 10).is_empty());
+		assert_eq!(held(1), 0);
+		assert_eq!(Balances::free_balance(1), 96);
+		assert_eq!(bond(2), 0);
+		assert_eq!(Balances::free_balance(2), 104);
+
+		// The tombstone keeps the owner from simply creating the claim again, but not the
+		// challenger.
+		assert_eq!(
+			Revocations::<Test>::get(&claim),
+			Some(Tombstone {
+				revoker: 2,
+				reason: CHALLENGE_UPHELD_REASON,
+				block_number: 1,
+				created_at: 1,
+			})
+		);
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ClaimRevoked
+		);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+		assert!(!Revocations::<Test>::contains_key(&claim));
+	})
+}
+
+#[test]
+fn expired_claim_drops_challenge() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let _ = PoeModule::create_claim_with_expiry(RuntimeOrigin::signed(1), claim.clone(), 3);
+		let _ = PoeModule::challenge_claim(RuntimeOrigin::signed(2), claim.clone());
+
+		System::set_block_number(3);
+		PoeModule::on_idle(3, Weight::MAX);
+		System::assert_has_event(Event::ChallengeDropped(2, claim.clone()).into());
+		assert!(Disputes::<Test>::get(&claim).is_none());
+		assert_eq!(bond(2), 0);
+	})
+}
+
+#[test]
+fn claims_record_timestamp() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		Timestamp::set_timestamp(6_000);
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.timestamp), Some(6_000));
+
+		System::set_block_number(2);
+		Timestamp::set_timestamp(12_000);
+		let _ = PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2);
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.timestamp), Some(12_000));
+	})
+}
+
+#[test]
+fn reveal_claim_keeps_commit_timestamp() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = H256::repeat_byte(9);
+		Timestamp::set_timestamp(6_000);
+		let salted_hash = BlakeTwo256::hash_of(&(&claim, &salt));
+		let _ = PoeModule::commit_claim(RuntimeOrigin::signed(1), salted_hash);
+
+		System::set_block_number(3);
+		Timestamp::set_timestamp(18_000);
+		assert_ok!(PoeModule::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), salt));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.timestamp), Some(6_000));
+	})
+}
+
+#[test]
+fn migration_to_v1_translates_baseline_claims() {
+	new_test_ext().execute_with(|| {
+		let claims: [BoundedVec<u8, ConstU32<32>>; 3] =
+			[vec![0, 1], vec![1, 0], vec![2]].map(|claim| BoundedVec::try_from(claim).unwrap());
+		// Claims as the original pallet stored them: `(owner, block_number)`.
+		for (claim, old) in claims.iter().zip([(1u64, 1u64), (1, 2), (2, 3)]) {
+			frame_support::storage::unhashed::put(&Proofs::<Test>::hashed_key_for(claim), &old);
+		}
+		StorageVersion::new(0).put::<PoeModule>();
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			migrations::v1::Proofs::<Test>::get(&claims[1]).map(|info| (info.owner, info.deposit)),
+			Some((1, 0))
+		);
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert_eq!(
+			Proofs::<Test>::get(&claims[1]),
+			Some(ClaimInfo {
+				owner: 1,
+				block_number: 2,
+				created_at: 2,
+				timestamp: 0,
+				depositor: 1,
+				deposit: 0,
+				expires_at: None,
+				kind: ClaimKind::Document,
+			})
+		);
+		assert_eq!(OwnedClaimCount::<Test>::get(1), 2);
+		assert_eq!(OwnedClaimCount::<Test>::get(2), 1);
+		assert_eq!(PoeModule::claims_of(&2, 0, 10), vec![claims[2].to_vec()]);
+		assert_eq!(ExpiryCursor::<Test>::get(), 1);
+		assert_eq!(CommitmentCursor::<Test>::get(), 1);
+
+		// Migrated claims behave like any other.
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claims[0].clone()));
+		assert_eq!(OwnedClaimCount::<Test>::get(1), 1);
+		assert_eq!(Balances::free_balance(1), 100);
+	})
+}
+
+#[test]
+fn migration_to_v2_records_creation_blocks() {
+	new_test_ext().execute_with(|| {
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let revoked: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![1, 0]).unwrap();
+		migrations::v1::Proofs::<Test>::insert(
+			&claim,
+			migrations::v1::ClaimInfo {
+				owner: 1,
+				block_number: 3,
+				timestamp: 3_000,
+				depositor: 1,
+				deposit: 4,
+				expires_at: Some(9),
+				kind: ClaimKind::Document,
+			},
+		);
+		frame_support::storage::unhashed::put(
+			&Revocations::<Test>::hashed_key_for(&revoked),
+			&migrations::v2::Tombstone { revoker: 2u64, reason: 7u16, block_number: 4u64 },
+		);
+		StorageVersion::new(1).put::<PoeModule>();
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert_eq!(
+			Proofs::<Test>::get(&claim).map(|info| (info.block_number, info.created_at)),
+			Some((3, 3))
+		);
+		assert_eq!(
+			Revocations::<Test>::get(&revoked),
+			Some(Tombstone { revoker: 2, reason: 7, block_number: 4, created_at: 0 })
+		);
+	})
+}
+
+fn namespace() -> BoundedVec<u8, ConstU32<8>> {
+	let namespace = BoundedVec::try_from(b"acme".to_vec()).unwrap();
+	assert_ok!(PoeModule::register_namespace(RuntimeOrigin::signed(1), namespace.clone()));
+	namespace
+}
+
+#[test]
+fn register_namespace_holds_deposit() {
+	new_test_ext().execute_with(|| {
+		let namespace = namespace();
+		System::assert_last_event(Event::NamespaceRegistered(1, namespace.clone()).into());
+		assert_eq!(Balances::balance_on_hold(&HoldReason::NamespaceDeposit.into(), &1), 20);
+
+		assert_noop!(
+			PoeModule::register_namespace(RuntimeOrigin::signed(2), namespace.clone()),
+			Error::<Test>::NamespaceAlreadyExist
+		);
+
+		assert_ok!(PoeModule::unregister_namespace(RuntimeOrigin::signed(1), namespace.clone()));
+		assert!(Namespaces::<Test>::get(&namespace).is_none());
+		assert_eq!(Balances::balance_on_hold(&HoldReason::NamespaceDeposit.into(), &1), 0);
+	})
+}
+
+#[test]
+fn namespaces_keep_claims_apart() {
+	new_test_ext().execute_with(|| {
+		let acme = namespace();
+		let other = BoundedVec::try_from(b"other".to_vec()).unwrap();
+		let _ = PoeModule::register_namespace(RuntimeOrigin::signed(2), other.clone());
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+
+		// Account 2 claiming first, globally or in its own namespace, does not block account 1.
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone());
+		let _ = PoeModule::create_namespaced_claim(RuntimeOrigin::signed(2), other, claim.clone());
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(1),
+			acme.clone(),
+			claim.clone()
+		));
+		System::assert_last_event(
+			Event::NamespacedClaimCreated(1, acme.clone(), claim.clone()).into(),
+		);
+
+		assert_eq!(
+			PoeModule::get_namespaced_claim(acme.to_vec(), claim.to_vec()).map(|info| info.owner),
+			Some(1)
+		);
+		assert_eq!(held(1), 4);
+		assert_noop!(
+			PoeModule::create_namespaced_claim(RuntimeOrigin::signed(1), acme.clone(), claim),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert_noop!(
+			PoeModule::unregister_namespace(RuntimeOrigin::signed(1), acme),
+			Error::<Test>::NamespaceNotEmpty
+		);
+	})
+}
+
+#[test]
+fn only_writers_create_namespaced_claims() {
+	new_test_ext().execute_with(|| {
+		let namespace = namespace();
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+
+		assert_noop!(
+			PoeModule::create_namespaced_claim(
+				RuntimeOrigin::signed(2),
+				namespace.clone(),
+				claim.clone()
+			),
+			Error::<Test>::NotNamespaceWriter
+		);
+		assert_noop!(
+			PoeModule::add_writer(RuntimeOrigin::signed(2), namespace.clone(), 2),
+			Error::<Test>::NotNamespaceOwner
+		);
+
+		assert_ok!(PoeModule::add_writer(RuntimeOrigin::signed(1), namespace.clone(), 2));
+		System::assert_last_event(Event::WriterAdded(namespace.clone(), 2).into());
+		assert_noop!(
+			PoeModule::add_writer(RuntimeOrigin::signed(1), namespace.clone(), 2),
+			Error::<Test>::AlreadyWriter
+		);
+		let _ = PoeModule::add_writer(RuntimeOrigin::signed(1), namespace.clone(), 3);
+		assert_noop!(
+			PoeModule::add_writer(RuntimeOrigin::signed(1), namespace.clone(), 4),
+			Error::<Test>::TooManyWriters
+		);
+
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(2),
+			namespace.clone(),
+			claim.clone()
+		));
+		assert_eq!(held(2), 4);
+
+		assert_ok!(PoeModule::remove_writer(RuntimeOrigin::signed(1), namespace.clone(), 2));
+		assert_noop!(
+			PoeModule::create_namespaced_claim(
+				RuntimeOrigin::signed(2),
+				namespace.clone(),
+				BoundedVec::try_from(vec![2]).unwrap()
+			),
+			Error::<Test>::NotNamespaceWriter
+		);
+
+		// The namespace owner can revoke claims of former writers.
+		assert_ok!(PoeModule::revoke_namespaced_claim(
+			RuntimeOrigin::signed(1),
+			namespace.clone(),
+			claim.clone()
+		));
+		System::assert_last_event(Event::NamespacedClaimRevoked(2, namespace.clone(), claim).into());
+		assert_eq!(held(2), 0);
+		assert_ok!(PoeModule::unregister_namespace(RuntimeOrigin::signed(1), namespace));
+	})
+}
+
+fn bounded(claim: &str) -> BoundedVec<u8, ConstU32<32>> {
+	BoundedVec::try_from(claim.as_bytes().to_vec()).unwrap()
+}
+
+/// Let `who` act on `claim` of account 1 as `permissions` allow.
+fn delegate(
+	claim: &BoundedVec<u8, ConstU32<32>>,
+	who: u64,
+	permissions: DelegatePermissions,
+	expires_at: Option<u64>,
+) -> DispatchResult {
+	PoeModule::add_delegate(RuntimeOrigin::signed(1), claim.clone(), who, permissions, expires_at)
+}
+
+fn batch(claims: &[&str]) -> BoundedVec<BoundedVec<u8, ConstU32<32>>, ConstU32<3>> {
+	BoundedVec::try_from(claims.iter().map(|claim| bounded(claim)).collect::<Vec<_>>()).unwrap()
+}
+
+#[test]
+fn create_claims_reports_each_item() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(1), batch(&["a", "a", "b"])));
+		System::assert_last_event(
+			Event::BatchCompleted(
+				1,
+				vec![Ok(()), Err(Error::<Test>::ProofAlreadyExist.into()), Ok(())],
+			)
+			.into(),
+		);
+
+		assert!(PoeModule::get_claim(b"a".to_vec()).is_some());
+		assert!(PoeModule::get_claim(b"b".to_vec()).is_some());
+		assert_eq!(OwnedClaimCount::<Test>::get(1), 2);
+		assert_eq!(held(1), 6);
+	})
+}
+
+#[test]
+fn failed_batch_items_leave_no_trace() {
+	new_test_ext().execute_with(|| {
+		// Account 4 is indexed as the owner before its deposit turns out to be unaffordable.
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(4), batch(&["a"])));
+		System::assert_last_event(
+			Event::BatchCompleted(4, vec![Err(TokenError::FundsUnavailable.into())]).into(),
+		);
+
+		assert!(PoeModule::get_claim(b"a".to_vec()).is_none());
+		assert!(PoeModule::claims_of(&4, 0, 10).is_empty());
+		assert_eq!(OwnedClaimCount::<Test>::get(4), 0);
+	})
+}
+
+#[test]
+fn revoke_and_transfer_claims_report_each_item() {
+	new_test_ext().execute_with(|| {
+		let _ = PoeModule::create_claims(RuntimeOrigin::signed(1), batch(&["a", "b", "c"]));
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(2), bounded("d"));
+
+		assert_ok!(PoeModule::revoke_claims(RuntimeOrigin::signed(1), batch(&["a", "d", "e"])));
+		System::assert_last_event(
+			Event::BatchCompleted(
+				1,
+				vec![
+					Ok(()),
+					Err(Error::<Test>::NotClaimOwner.into()),
+					Err(Error::<Test>::ClaimNotExist.into()),
+				],
+			)
+			.into(),
+		);
+		assert!(PoeModule::get_claim(b"a".to_vec()).is_none());
+		assert!(PoeModule::get_claim(b"d".to_vec()).is_some());
+
+		let transfers = BoundedVec::try_from(vec![(bounded("b"), 2), (bounded("c"), 1)]).unwrap();
+		assert_ok!(PoeModule::transfer_claims(RuntimeOrigin::signed(1), transfers));
+		System::assert_last_event(
+			Event::BatchCompleted(1, vec![Ok(()), Err(Error::<Test>::OfferToSelf.into())]).into(),
+		);
+		assert_eq!(PendingOffers::<Test>::get(bounded("b")), Some(2));
+	})
+}
+
+#[test]
+fn claims_created_counts_claims_of_a_call() {
+	assert_eq!(Call::<Test>::create_claim { claim: bounded("a") }.claims_created(), 1);
+	let claims = batch(&["a", "b", "c"]);
+	assert_eq!(Call::<Test>::create_claims { claims }.claims_created(), 3);
+	assert_eq!(Call::<Test>::revoke_claim { claim: bounded("a") }.claims_created(), 0);
+}
+
+fn validate_claim_rate(who: u64, call: Call<Test>) -> TransactionValidity {
+	LimitClaimRate::<Test>::new().validate(&who, &call.into(), &Default::default(), 0)
+}
+
+fn pre_dispatch_claim_rate(who: u64, call: Call<Test>) -> Result<(), TransactionValidityError> {
+	LimitClaimRate::<Test>::new().pre_dispatch(&who, &call.into(), &Default::default(), 0)
+}
+
+#[test]
+fn claim_rate_is_recorded_on_pre_dispatch() {
+	new_test_ext().execute_with(|| {
+		// Validating alone does not use up the rate.
+		assert_ok!(validate_claim_rate(1, Call::create_claim { claim: bounded("a") }));
+		assert_eq!(ClaimRate::<Test>::get(1), None);
+
+		assert_ok!(pre_dispatch_claim_rate(1, Call::create_claim { claim: bounded("a") }));
+		// Block 1 is in the window starting at block 0.
+		assert_eq!(ClaimRate::<Test>::get(1), Some((0, 1)));
+		// Calls creating no claims are not counted.
+		assert_ok!(pre_dispatch_claim_rate(1, Call::revoke_claim { claim: bounded("a") }));
+		assert_eq!(ClaimRate::<Test>::get(1), Some((0, 1)));
+	})
+}
+
+#[test]
+fn claim_rate_rejects_claims_over_the_limit() {
+	new_test_ext().execute_with(|| {
+		let exceeded: TransactionValidityError =
+			InvalidTransaction::Custom(claim_rate::CLAIM_RATE_EXCEEDED).into();
+		// A batch over the limit of 2 claims per window is rejected as a whole.
+		assert_eq!(
+			validate_claim_rate(1, Call::create_claims { claims: batch(&["a", "b", "c"]) }),
+			Err(exceeded)
+		);
+
+		assert_ok!(pre_dispatch_claim_rate(1, Call::create_claims { claims: batch(&["a", "b"]) }));
+		assert_eq!(
+			validate_claim_rate(1, Call::create_claim { claim: bounded("c") }),
+			Err(exceeded)
+		);
+		assert_eq!(
+			pre_dispatch_claim_rate(1, Call::create_claim { claim: bounded("c") }),
+			Err(exceeded)
+		);
+		assert_eq!(ClaimRate::<Test>::get(1), Some((0, 2)));
+		// Other accounts and calls creating no claims are not limited.
+		assert_ok!(validate_claim_rate(2, Call::create_claim { claim: bounded("c") }));
+		assert_ok!(validate_claim_rate(1, Call::revoke_claim { claim: bounded("a") }));
+	})
+}
+
+#[test]
+fn claim_rate_resets_in_the_next_window() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(pre_dispatch_claim_rate(1, Call::create_claims { claims: batch(&["a", "b"]) }));
+
+		System::set_block_number(9);
+		assert!(validate_claim_rate(1, Call::create_claim { claim: bounded("c") }).is_err());
+
+		// The window of 10 blocks ends at block 10.
+		System::set_block_number(10);
+		assert_ok!(validate_claim_rate(1, Call::create_claim { claim: bounded("c") }));
+		assert_ok!(pre_dispatch_claim_rate(1, Call::create_claim { claim: bounded("c") }));
+		assert_eq!(ClaimRate::<Test>::get(1), Some((10, 1)));
+	})
+}
+
+#[test]
+fn add_delegate_works() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		let permissions = DelegatePermissions::REVOKE | DelegatePermissions::TRANSFER;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+
+		assert_ok!(delegate(&claim, 2, permissions, Some(5)));
+		System::assert_last_event(
+			Event::DelegateAdded(claim.clone(), 2, permissions, Some(5)).into(),
+		);
+
+		// Adding the same delegate again replaces its entry.
+		assert_ok!(delegate(&claim, 2, DelegatePermissions::REVOKE, None));
+		assert_eq!(
+			Delegates::<Test>::get(&claim).into_inner(),
+			vec![Delegate { who: 2, permissions: DelegatePermissions::REVOKE, expires_at: None }]
+		);
+	})
+}
+
+#[test]
+fn add_delegate_failed_with_invalid_arguments() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		let revoke = DelegatePermissions::REVOKE;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+
+		assert_noop!(
+			PoeModule::add_delegate(RuntimeOrigin::signed(2), claim.clone(), 3, revoke, None),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::add_delegate(RuntimeOrigin::signed(1), bounded("b"), 3, revoke, None),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_noop!(delegate(&claim, 1, revoke, None), Error::<Test>::DelegateToSelf);
+		for permissions in [DelegatePermissions(0), DelegatePermissions(4)] {
+			assert_noop!(delegate(&claim, 3, permissions, None), Error::<Test>::InvalidPermissions);
+		}
+		assert_noop!(delegate(&claim, 3, revoke, Some(1)), Error::<Test>::ExpiryInPast);
+
+		assert_ok!(delegate(&claim, 2, revoke, None));
+		assert_ok!(delegate(&claim, 3, revoke, None));
+		assert_noop!(delegate(&claim, 4, revoke, None), Error::<Test>::TooManyDelegates);
+	})
+}
+
+#[test]
+fn delegate_acts_within_its_permissions() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(delegate(&claim, 2, DelegatePermissions::TRANSFER, None));
+		assert_ok!(delegate(&claim, 3, DelegatePermissions::REVOKE, None));
+
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(3), claim.clone(), 4),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(2), claim.clone(), 1),
+			Error::<Test>::OfferToSelf
+		);
+
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(2), claim.clone(), 4));
+		System::assert_last_event(Event::ClaimOffered(1, 4, claim.clone()).into());
+
+		assert_ok!(PoeModule::revoke_claim_with_reason(RuntimeOrigin::signed(3), claim.clone(), 7));
+		assert!(Proofs::<Test>::get(&claim).is_none());
+		assert!(Delegates::<Test>::get(&claim).is_empty());
+		assert_eq!(Revocations::<Test>::get(&claim).map(|tombstone| tombstone.revoker), Some(3));
+		assert_eq!(held(1), 0);
+	})
+}
+
+#[test]
+fn delegate_cancels_offer_within_its_permissions() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(delegate(&claim, 2, DelegatePermissions::TRANSFER, None));
+		assert_ok!(delegate(&claim, 3, DelegatePermissions::REVOKE, None));
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 4));
+
+		assert_noop!(
+			PoeModule::cancel_offer(RuntimeOrigin::signed(3), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(2), claim.clone()));
+		System::assert_last_event(Event::OfferCancelled(1, claim.clone()).into());
+		assert_eq!(PendingOffers::<Test>::get(&claim), None);
+	})
+}
+
+#[test]
+fn delegation_expires() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		let revoke = DelegatePermissions::REVOKE;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(delegate(&claim, 2, revoke, Some(3)));
+		assert_ok!(delegate(&claim, 3, revoke, None));
+
+		System::set_block_number(3);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		// The expired delegation makes room for another one.
+		assert_ok!(delegate(&claim, 4, revoke, None));
+		assert_eq!(
+			Delegates::<Test>::get(&claim).iter().map(|delegate| delegate.who).collect::<Vec<_>>(),
+			vec![3, 4]
+		);
+	})
+}
+
+#[test]
+fn remove_delegate_works() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		let all = DelegatePermissions::ALL;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(delegate(&claim, 2, all, None));
+		assert_ok!(delegate(&claim, 3, all, None));
+
+		assert_noop!(
+			PoeModule::remove_delegate(RuntimeOrigin::signed(3), claim.clone(), 2),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::remove_delegate(RuntimeOrigin::signed(1), claim.clone(), 4),
+			Error::<Test>::NotDelegate
+		);
+
+		assert_ok!(PoeModule::remove_delegate(RuntimeOrigin::signed(1), claim.clone(), 2));
+		System::assert_last_event(Event::DelegateRemoved(claim.clone(), 2).into());
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		// A delegate can step down by itself.
+		assert_ok!(PoeModule::remove_delegate(RuntimeOrigin::signed(3), claim.clone(), 3));
+		assert!(!Delegates::<Test>::contains_key(&claim));
+	})
+}
+
+#[test]
+fn transfer_drops_delegates() {
+	new_test_ext().execute_with(|| {
+		let claim = bounded("a");
+		let all = DelegatePermissions::ALL;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(delegate(&claim, 2, all, None));
+
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(2), claim.clone(), 3));
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(3), claim.clone()));
+
+		assert!(!Delegates::<Test>::contains_key(&claim));
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+	})
+}
@@ -0,0 +1,278 @@
+//! Offline verification of proof-of-existence claims.
+//!
+//! `poe_getClaimProof` returns a claim together with a storage proof of its `Proofs` entry.
+//! Anyone holding the header of that block can check the proof against the header's state
+//! root, without trusting the node that produced it.
+//!
+//! The crate does not depend on the pallet. It decodes claims with its own copy of their
+//! storage layout, and takes the name the runtime gives the pallet and the runtime's types as
+//! parameters.
+
+#![warn(missing_docs)]
+
+use codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_core::{
+    hashing::{blake2_128, twox_128},
+    Bytes,
+};
+use sp_runtime::traits::{Header as HeaderT, UniqueSaturatedInto};
+use sp_trie::StorageProof;
+
+/// Hash function a digest or Merkle tree was computed with.
+#[allow(missing_docs)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    Blake2_256,
+    Keccak256,
+    Sha2_256,
+}
+
+/// What a claim stands for.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum ClaimKind {
+    /// A single document.
+    Document,
+    /// The root of a Merkle tree over many documents.
+    MerkleRoot(HashAlgorithm),
+    /// The digest of a single document.
+    Digest(HashAlgorithm),
+}
+
+/// A claim as stored in `Proofs`, laid out like `pallet_poe::ClaimInfo`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ClaimInfo<AccountId, BlockNumber, Balance, Moment> {
+    /// Current owner of the claim.
+    pub owner: AccountId,
+    /// Block in which the claim was created or last changed hands.
+    pub block_number: BlockNumber,
+    /// Block in which the claim was created.
+    pub created_at: BlockNumber,
+    /// Time of `block_number`, zero for claims made before timestamps were recorded.
+    pub timestamp: Moment,
+    /// Account the deposit is held from.
+    pub depositor: AccountId,
+    /// Amount held from `depositor`.
+    pub deposit: Balance,
+    /// Block at which the claim expires, if it is time-limited.
+    pub expires_at: Option<BlockNumber>,
+    /// What the claim stands for.
+    pub kind: ClaimKind,
+}
+
+/// A claim and the proof of its storage entry, as returned by `poe_getClaimProof`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimProof<Hash> {
+    /// Block the proof was read at.
+    pub at: Hash,
+    /// Storage key of the claim in `Proofs`.
+    pub key: Bytes,
+    /// SCALE-encoded claim, `None` if there is no such claim.
+    pub value: Option<Bytes>,
+    /// Trie nodes proving `value` under `key`.
+    pub proof: Vec<Bytes>,
+}
+
+/// Reasons a claim proof is rejected.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The proof was read at another block than the one of the header.
+    #[error("proof is for block {proof}, header is for block {header}")]
+    WrongBlock {
+        /// Hash of the header.
+        header: String,
+        /// Block the proof was read at.
+        proof: String,
+    },
+    /// The proof is not about the storage key of the claim.
+    #[error("proof is not about the storage key of the claim")]
+    WrongKey,
+    /// The trie nodes do not lead from the state root to the key.
+    #[error("invalid storage proof: {0}")]
+    InvalidProof(String),
+    /// The value in the proof is not the one returned alongside it.
+    #[error("the proven value does not match the returned one")]
+    ValueMismatch,
+    /// The proven value is not a claim.
+    #[error("cannot decode the claim: {0}")]
+    Decode(#[from] codec::Error),
+    /// The claim is still stored, but expired at or before the block of the header.
+    #[error("the claim expired at block {0}")]
+    Expired(u64),
+}
+
+/// Storage key of `claim` in the `Proofs` map of the pallet named `pallet` in the runtime.
+pub fn proofs_key(pallet: &str, claim: &[u8]) -> Vec<u8> {
+    let encoded = claim.encode();
+
+    [
+        &twox_128(pallet.as_bytes())[..],
+        &twox_128(b"Proofs")[..],
+        &blake2_128(&encoded)[..],
+        &encoded[..],
+    ]
+    .concat()
+}
+
+/// Check `proof` for `claim` in the pallet named `pallet` against the state root of `header`.
+///
+/// Returns the proven claim, or `None` if the proof shows that there was no such claim. A claim
+/// that has expired by the block of `header` is rejected, like the runtime treats it as gone
+/// before it is swept.
+pub fn verify<H, AccountId, Balance, Moment>(
+    pallet: &str,
+    header: &H,
+    claim: &[u8],
+    proof: &ClaimProof<H::Hash>,
+) -> Result<Option<ClaimInfo<AccountId, H::Number, Balance, Moment>>, Error>
+where
+    H: HeaderT,
+    AccountId: Decode,
+    Balance: Decode,
+    Moment: Decode,
+{
+    let hash = header.hash();
+    if hash != proof.at {
+        return Err(Error::WrongBlock {
+            header: format!("{:?}", hash),
+            proof: format!("{:?}", proof.at),
+        });
+    }
+
+    let key = proofs_key(pallet, claim);
+    if key != proof.key.0 {
+        return Err(Error::WrongKey);
+    }
+
+    let nodes = StorageProof::new(proof.proof.iter().map(|node| node.0.clone()));
+    let mut values =
+        sp_state_machine::read_proof_check::<H::Hashing, _>(*header.state_root(), nodes, [&key])
+            .map_err(|e| Error::InvalidProof(format!("{:?}", e)))?;
+    let value = values.remove(&key).flatten();
+
+    if value.as_deref() != proof.value.as_ref().map(|v| &v.0[..]) {
+        return Err(Error::ValueMismatch);
+    }
+
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let info = ClaimInfo::<AccountId, H::Number, Balance, Moment>::decode(&mut &value[..])?;
+    if let Some(expires_at) = info.expires_at {
+        if expires_at <= *header.number() {
+            return Err(Error::Expired(expires_at.unique_saturated_into()));
+        }
+    }
+
+    Ok(Some(info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::H256;
+    use sp_runtime::{generic, traits::BlakeTwo256, AccountId32, Digest, StateVersion};
+    use sp_state_machine::{prove_read, Backend, InMemoryBackend};
+
+    type Header = generic::Header<u32, BlakeTwo256>;
+    type TestClaimInfo = ClaimInfo<AccountId32, u32, u128, u64>;
+
+    fn verify(
+        header: &Header,
+        claim: &[u8],
+        proof: &ClaimProof<H256>,
+    ) -> Result<Option<TestClaimInfo>, Error> {
+        super::verify("PoeModule", header, claim, proof)
+    }
+
+    fn claim_info(expires_at: Option<u32>) -> TestClaimInfo {
+        ClaimInfo {
+            owner: AccountId32::new([1; 32]),
+            block_number: 7,
+            created_at: 7,
+            timestamp: 42_000,
+            depositor: AccountId32::new([1; 32]),
+            deposit: 1_000,
+            expires_at,
+            kind: ClaimKind::Document,
+        }
+    }
+
+    /// A header and a proof of `claim` in a state holding only `stored`.
+    fn prove(stored: &[u8], claim: &[u8]) -> (Header, ClaimProof<H256>) {
+        prove_with(stored, claim_info(None), claim)
+    }
+
+    /// A header and a proof of `claim` in a state holding only `info` under `stored`.
+    fn prove_with(stored: &[u8], info: TestClaimInfo, claim: &[u8]) -> (Header, ClaimProof<H256>) {
+        let backend = InMemoryBackend::<BlakeTwo256>::from((
+            vec![(None, vec![(proofs_key("PoeModule", stored), Some(info.encode()))])],
+            StateVersion::V1,
+        ));
+        let state_root = *backend.root();
+        let key = proofs_key("PoeModule", claim);
+        let value = backend.storage(&key).unwrap().map(Bytes);
+        let nodes = prove_read(backend, [&key]).unwrap();
+
+        let header = Header::new(7, H256::zero(), state_root, H256::zero(), Digest::default());
+        let proof = ClaimProof {
+            at: header.hash(),
+            key: Bytes(key),
+            value,
+            proof: nodes.into_iter_nodes().map(Bytes).collect(),
+        };
+
+        (header, proof)
+    }
+
+    #[test]
+    fn verify_proves_existing_claim() {
+        let (header, proof) = prove(b"doc", b"doc");
+
+        assert_eq!(verify(&header, b"doc", &proof).unwrap(), Some(claim_info(None)));
+    }
+
+    #[test]
+    fn verify_rejects_expired_claim() {
+        // The header is for block 7.
+        let (header, proof) = prove_with(b"doc", claim_info(Some(8)), b"doc");
+        assert_eq!(verify(&header, b"doc", &proof).unwrap(), Some(claim_info(Some(8))));
+
+        let (header, proof) = prove_with(b"doc", claim_info(Some(7)), b"doc");
+        assert!(matches!(verify(&header, b"doc", &proof), Err(Error::Expired(7))));
+    }
+
+    #[test]
+    fn verify_rejects_proof_for_another_pallet() {
+        let (header, proof) = prove(b"doc", b"doc");
+
+        assert!(matches!(
+            super::verify::<_, AccountId32, u128, u64>("OtherPoe", &header, b"doc", &proof),
+            Err(Error::WrongKey)
+        ));
+    }
+
+    #[test]
+    fn verify_proves_missing_claim() {
+        let (header, proof) = prove(b"doc", b"other");
+
+        assert_eq!(verify(&header, b"other", &proof).unwrap(), None);
+    }
+
+    #[test]
+    fn verify_rejects_tampered_proofs() {
+        let (header, mut proof) = prove(b"doc", b"doc");
+        proof.value = None;
+        assert!(matches!(verify(&header, b"doc", &proof), Err(Error::ValueMismatch)));
+
+        let (header, proof) = prove(b"doc", b"doc");
+        assert!(matches!(verify(&header, b"other", &proof), Err(Error::WrongKey)));
+
+        let (mut header, mut proof) = prove(b"doc", b"doc");
+        header.state_root = H256::repeat_byte(1);
+        assert!(matches!(verify(&header, b"doc", &proof), Err(Error::WrongBlock { .. })));
+        proof.at = header.hash();
+        assert!(matches!(verify(&header, b"doc", &proof), Err(Error::InvalidProof(_))));
+    }
+}
@@ -0,0 +1,81 @@
+//! Check a `poe_getClaimProof` response against a block header, offline.
+//!
+//! ```text
+//! poe-verify --header header.json --proof proof.json --claim 0x0102
+//! ```
+//!
+//! `header.json` is the block header as returned by `chain_getHeader`, obtained from a source
+//! the verifier trusts, and `proof.json` the result of `poe_getClaimProof` for that block.
+//! Claims are decoded with the types of the solochain runtime.
+
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use poe_verify::{verify, ClaimInfo, ClaimProof};
+use sp_core::{Bytes, H256};
+use sp_runtime::{
+    generic,
+    traits::{BlakeTwo256, Header as HeaderT},
+    AccountId32,
+};
+
+/// Header of the solochain's blocks.
+type Header = generic::Header<u32, BlakeTwo256>;
+
+/// A claim as stored by the solochain runtime.
+type SolochainClaimInfo = ClaimInfo<AccountId32, u32, u128, u64>;
+
+#[derive(Debug, Parser)]
+#[command(about = "Verify a proof-of-existence claim against a block header")]
+struct Cli {
+    /// JSON file with the block header.
+    #[arg(long)]
+    header: PathBuf,
+
+    /// JSON file with the `poe_getClaimProof` result.
+    #[arg(long)]
+    proof: PathBuf,
+
+    /// The claim, hex-encoded with a `0x` prefix.
+    #[arg(long)]
+    claim: Bytes,
+
+    /// Name of the proof-of-existence pallet in the runtime's `construct_runtime!`.
+    #[arg(long, default_value = "PoeModule")]
+    pallet: String,
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &PathBuf) -> Result<T, String> {
+    let data = fs::read(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    serde_json::from_slice(&data).map_err(|e| format!("cannot parse {}: {}", path.display(), e))
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let header: Header = read_json(&cli.header)?;
+    let proof: ClaimProof<H256> = read_json(&cli.proof)?;
+
+    let block = format!("block #{} ({:?})", header.number(), header.hash());
+    let claim: Option<SolochainClaimInfo> =
+        verify(&cli.pallet, &header, &cli.claim, &proof).map_err(|e| e.to_string())?;
+    match claim {
+        Some(info) => {
+            println!("claim exists at {}", block);
+            println!("  owner:        {}", info.owner);
+            println!("  block number: {}", info.block_number);
+            println!("  timestamp:    {}", info.timestamp);
+        }
+        None => println!("claim does not exist at {}", block),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("verification failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
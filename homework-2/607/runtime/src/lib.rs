@@ -6,7 +6,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H256};
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
@@ -34,6 +34,7 @@ pub use frame_support::{
     StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -60,6 +61,9 @@ pub type Balance = u128;
 /// Index of a transaction in the chain.
 pub type Nonce = u32;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
@@ -99,10 +103,11 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 102,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    // Bumped for `LimitClaimRate` joining `SignedExtra`.
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -199,8 +204,7 @@ impl pallet_grandpa::Config for Runtime {
 }
 
 impl pallet_timestamp::Config for Runtime {
-    /// A timestamp: milliseconds since the unix epoch.
-    type Moment = u64;
+    type Moment = Moment;
     type OnTimestampSet = Aura;
     type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
     type WeightInfo = ();
@@ -223,7 +227,7 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
@@ -254,8 +258,34 @@ impl pallet_template::Config for Runtime {
 
 impl pallet_poe::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type MaxClaimLength = ConstU32<3>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type DepositBase = ConstU128<{ 2 * EXISTENTIAL_DEPOSIT }>;
+    type DepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 5 }>;
+    type MaxClaimLength = ConstU32<64>;
+    type MaxClaimsPerOwner = ConstU32<1_000>;
+    type MaxProofDepth = ConstU32<32>;
+    type MaxMimeTypeLength = ConstU32<64>;
+    type MaxUriLength = ConstU32<256>;
+    type RevealWindow = ConstU32<{ 7 * DAYS }>;
+    type MaxCoOwners = ConstU32<16>;
+    type ApprovalTimeout = ConstU32<DAYS>;
+    type MaxProvenance = ConstU32<32>;
+    type ChallengeBond = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type ArbitratorOrigin = EnsureRoot<AccountId>;
+    type NamespaceDeposit = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+    type MaxNamespaceLength = ConstU32<32>;
+    type MaxNamespaceWriters = ConstU32<64>;
+    type MaxBatchSize = ConstU32<128>;
+    type MaxDelegates = ConstU32<16>;
+    type ClaimRateWindow = ConstU32<{ 10 * MINUTES }>;
+    type MaxClaimsPerWindow = ConstU32<20>;
+    type Time = Timestamp;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
     type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -320,13 +350,17 @@ pub type SignedExtra = (
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_poe::LimitClaimRate<Runtime>,
 );
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_poe::migrations::v1::MigrateToV1<Runtime>,
+    pallet_poe::migrations::v2::MigrateToV2<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -480,6 +514,74 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, Moment> for Runtime {
+        fn get_claim(
+            claim: Vec<u8>,
+        ) -> Option<pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber, Moment>> {
+            PoeModule::get_claim(claim).map(|info| pallet_poe_runtime_api::ClaimDetails {
+                owner: info.owner,
+                block_number: info.block_number,
+                timestamp: info.timestamp,
+            })
+        }
+
+        fn get_namespaced_claim(
+            namespace: Vec<u8>,
+            claim: Vec<u8>,
+        ) -> Option<pallet_poe_runtime_api::ClaimDetails<AccountId, BlockNumber, Moment>> {
+            PoeModule::get_namespaced_claim(namespace, claim).map(|info| {
+                pallet_poe_runtime_api::ClaimDetails {
+                    owner: info.owner,
+                    block_number: info.block_number,
+                    timestamp: info.timestamp,
+                }
+            })
+        }
+
+        fn claim_status(
+            claim: Vec<u8>,
+        ) -> pallet_poe_runtime_api::ClaimStatus<AccountId, BlockNumber> {
+            use pallet_poe_runtime_api::ClaimStatus;
+
+            if PoeModule::get_claim(claim.clone()).is_some() {
+                return ClaimStatus::Active;
+            }
+            match PoeModule::tombstone(claim) {
+                Some(tombstone) => ClaimStatus::Revoked {
+                    revoker: tombstone.revoker,
+                    reason: tombstone.reason,
+                    block_number: tombstone.block_number,
+                },
+                None => ClaimStatus::Unknown,
+            }
+        }
+
+        fn claim_count(owner: AccountId) -> u32 {
+            pallet_poe::OwnedClaimCount::<Runtime>::get(owner)
+        }
+
+        fn claims_of(owner: AccountId, offset: u32, limit: u32) -> Vec<Vec<u8>> {
+            PoeModule::claims_of(&owner, offset, limit)
+        }
+
+        fn provenance(
+            claim: Vec<u8>,
+        ) -> Vec<pallet_poe_runtime_api::ProvenanceRecord<AccountId, BlockNumber, Moment>> {
+            PoeModule::provenance(claim)
+                .into_iter()
+                .map(|record| pallet_poe_runtime_api::ProvenanceRecord {
+                    owner: record.owner,
+                    block_number: record.block_number,
+                    timestamp: record.timestamp,
+                })
+                .collect()
+        }
+
+        fn verify_inclusion(root: H256, leaf: H256, index: u32, proof: Vec<H256>) -> bool {
+            PoeModule::do_verify_inclusion(root, leaf, index, &proof).is_ok()
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,
@@ -0,0 +1,27 @@
+//! Runtime API definition for the kitties pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+pub use pallet_kitties::genes::{BodyColor, EyeShape, KittyTraits, Pattern, RarityTier};
+
+/// A kitty with the traits its DNA expresses.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct KittyDetails {
+    pub gene: [u8; 16],
+    pub traits: KittyTraits,
+    /// 0 for created kitties, one more than the older parent for bred ones.
+    pub generation: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait KittiesApi {
+        /// The kitty `kitty_id` with its decoded traits, if there is such a kitty.
+        fn kitty_details(kitty_id: u32) -> Option<KittyDetails>;
+
+        /// The traits `gene` expresses, for genes that do not belong to a kitty yet.
+        fn decode_gene(gene: [u8; 16]) -> KittyTraits;
+    }
+}
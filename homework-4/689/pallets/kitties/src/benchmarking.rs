@@ -7,30 +7,64 @@ use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use frame_support::traits::Currency;
+use frame_support::sp_runtime::traits::Hash;
+
+fn next_block<T: Config>() {
+    frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+}
+
+/// Create a kitty for `owner` and hatch it with a seed committed to before the request.
+fn create_kitty<T: Config>(owner: &T::AccountId) -> Result<u32, BenchmarkError> {
+    let revealer: T::AccountId = account("revealer", 0, 0);
+    T::Currency::make_free_balance_be(&revealer, 3000u32.into());
+    let seed = [1u8; 32];
+    let index = NextKittyId::<T>::get();
+
+    Pallet::<T>::commit_seed(RawOrigin::Signed(revealer.clone()).into(), T::Hashing::hash(&seed))?;
+    next_block::<T>();
+    Pallet::<T>::create(RawOrigin::Signed(owner.clone()).into())?;
+    next_block::<T>();
+    Pallet::<T>::reveal_seed(RawOrigin::Signed(revealer).into(), seed)?;
+
+    Ok(index)
+}
+
+/// Queue `b` kitties of other accounts, none of which hatch before the benchmarked call.
+fn request_kitties<T: Config>(b: u32) -> Result<(), BenchmarkError> {
+    for i in 0..b {
+        let owner: T::AccountId = account("requester", i, 0);
+        T::Currency::make_free_balance_be(&owner, 3000u32.into());
+        Pallet::<T>::create(RawOrigin::Signed(owner).into())?;
+    }
+
+    Ok(())
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn create() -> Result<(), BenchmarkError> {
+    fn create(b: Linear<0, { T::MaxPendingBirths::get() - 1 }>) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, 3000u32.into());
+        request_kitties::<T>(b)?;
 
         #[extrinsic_call]   
         crate::create(RawOrigin::Signed(caller));
 
-        assert_eq!(Kitties::<T>::contains_key(0), true);
+        assert_eq!(PendingBirths::<T>::get().len() as u32, b + 1);
 
         Ok(())
     }    
 
     #[benchmark]
-    fn breed() -> Result<(), BenchmarkError> {
+    fn breed(b: Linear<0, { T::MaxPendingBirths::get() - 1 }>) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
         <T as pallet::Config>::Currency::make_free_balance_be(&caller,6000u32.into());
-        Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
-        Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+        create_kitty::<T>(&caller)?;
+        create_kitty::<T>(&caller)?;
+        request_kitties::<T>(b)?;
 
         assert_eq!(Kitties::<T>::contains_key(0), true);
         assert_eq!(Kitties::<T>::contains_key(1), true);
@@ -38,7 +72,7 @@ mod benchmarks {
         #[extrinsic_call]
         crate::breed(RawOrigin::Signed(caller), 0, 1);
 
-        assert_eq!(Kitties::<T>::contains_key(2), true);
+        assert_eq!(PendingBirths::<T>::get().last().map(|birth| birth.index), Some(b + 2));
         Ok(())
     }    
  
@@ -46,7 +80,7 @@ mod benchmarks {
     fn transfer() -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, 3000u32.into());
-        Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+        create_kitty::<T>(&caller)?;
         assert_eq!(Kitties::<T>::contains_key(0), true);
         assert_eq!(KittyOwner::<T>::contains_key(0), true);
         assert_eq!(KittyOwner::<T>::get(0).unwrap(), caller);
@@ -65,7 +99,7 @@ mod benchmarks {
     fn sale() -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, 3000u32.into());
-        Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+        create_kitty::<T>(&caller)?;
         assert_eq!(Kitties::<T>::contains_key(0), true);
 
         let price: BalanceOf<T> = 1000u32.into();
@@ -84,7 +118,7 @@ mod benchmarks {
     fn bid() -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
         T::Currency::make_free_balance_be(&caller, 3000u32.into());
-        Pallet::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+        create_kitty::<T>(&caller)?;
         let price: BalanceOf<T> = 1000u32.into();
         let to_block: BlockNumberFor<T> = 5u32.into();
         Pallet::<T>::sale(RawOrigin::Signed(caller.clone()).into(), 0, price, to_block)?;
@@ -102,5 +136,113 @@ mod benchmarks {
         Ok(())
     }    
 
+    #[benchmark]
+    fn commit_seed() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, 3000u32.into());
+        let commitment = T::Hashing::hash(&[1u8; 32]);
+
+        #[extrinsic_call]
+        crate::commit_seed(RawOrigin::Signed(caller.clone()), commitment);
+
+        assert_eq!(SeedCommitments::<T>::contains_key(&caller), true);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reveal_seed(b: Linear<0, { T::MaxPendingBirths::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, 3000u32.into());
+        let seed = [1u8; 32];
+        Pallet::<T>::commit_seed(RawOrigin::Signed(caller.clone()).into(), T::Hashing::hash(&seed))?;
+        next_block::<T>();
+
+        // Every pending kitty hatches on this reveal.
+        for i in 0..b {
+            let owner: T::AccountId = account("owner", i, 0);
+            T::Currency::make_free_balance_be(&owner, 3000u32.into());
+            Pallet::<T>::create(RawOrigin::Signed(owner).into())?;
+        }
+        next_block::<T>();
+
+        #[extrinsic_call]
+        crate::reveal_seed(RawOrigin::Signed(caller.clone()), seed);
+
+        assert_eq!(SeedCommitments::<T>::contains_key(&caller), false);
+        assert_eq!(PendingBirths::<T>::get().is_empty(), true);
+        assert_eq!(NextKittyId::<T>::get(), b);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn list_sire() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, 3000u32.into());
+        create_kitty::<T>(&caller)?;
+
+        let fee: BalanceOf<T> = 100u32.into();
+        #[extrinsic_call]
+        crate::list_sire(RawOrigin::Signed(caller), 0, fee);
+
+        assert_eq!(SireListings::<T>::get(0), Some(fee));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unlist_sire() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, 3000u32.into());
+        create_kitty::<T>(&caller)?;
+        Pallet::<T>::list_sire(RawOrigin::Signed(caller.clone()).into(), 0, 100u32.into())?;
+
+        #[extrinsic_call]
+        crate::unlist_sire(RawOrigin::Signed(caller), 0);
+
+        assert_eq!(SireListings::<T>::contains_key(0), false);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn breed_with_sire(b: Linear<0, { T::MaxPendingBirths::get() - 1 }>) -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        T::Currency::make_free_balance_be(&owner, 3000u32.into());
+        create_kitty::<T>(&owner)?;
+        let fee: BalanceOf<T> = 100u32.into();
+        Pallet::<T>::list_sire(RawOrigin::Signed(owner).into(), 0, fee)?;
+
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, 3000u32.into());
+        create_kitty::<T>(&caller)?;
+        request_kitties::<T>(b)?;
+
+        #[extrinsic_call]
+        crate::breed_with_sire(RawOrigin::Signed(caller.clone()), 0, 1, fee);
+
+        assert_eq!(PendingBirths::<T>::get().last().map(|birth| birth.owner.clone()), Some(caller));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn expire_seed() -> Result<(), BenchmarkError> {
+        let who: T::AccountId = account("revealer", 0, 0);
+        T::Currency::make_free_balance_be(&who, 3000u32.into());
+        Pallet::<T>::commit_seed(RawOrigin::Signed(who.clone()).into(), T::Hashing::hash(&[1u8; 32]))?;
+        let expired_at = frame_system::Pallet::<T>::block_number() + T::RevealPeriod::get() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(expired_at);
+
+        let caller: T::AccountId = whitelisted_caller();
+        #[extrinsic_call]
+        crate::expire_seed(RawOrigin::Signed(caller), who.clone());
+
+        assert_eq!(SeedCommitments::<T>::contains_key(&who), false);
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -4,6 +4,7 @@ use frame_support::pallet_macros::pallet_section;
 /// This can later be imported into the pallet using [`import_section`].
 #[pallet_section]
 mod config {
+    use frame_support::sp_runtime::Permill;
     use frame_support::traits::ReservableCurrency;
     use frame_system::offchain::SendTransactionTypes;
     
@@ -13,7 +14,7 @@ mod config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
-        /// A random value generator.
+        /// The source of kitty DNA, e.g. `RevealedRandomness`.
         type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
         
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
@@ -23,5 +24,30 @@ mod config {
 
         #[pallet::constant]
         type BidMargin: Get<BalanceOf<Self>>;
+
+        /// Probability of each allele a child inherits to mutate into a random one.
+        #[pallet::constant]
+        type MutationRate: Get<Permill>;
+
+        /// Blocks a kitty of generation 0 rests after breeding, growing linearly with generation.
+        #[pallet::constant]
+        type BreedingCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of children a kitty can breed.
+        #[pallet::constant]
+        type MaxChildrenPerKitty: Get<u32>;
+
+        /// Deposit reserved by `commit_seed`, returned on reveal and slashed if the seed is not
+        /// revealed within `RevealPeriod`.
+        #[pallet::constant]
+        type SeedDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks after its commitment within which a seed must be revealed.
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of kitties waiting to hatch at once.
+        #[pallet::constant]
+        type MaxPendingBirths: Get<u32>;
     }
 }
@@ -17,5 +17,17 @@ mod errors {
         StateError,
         NewBidError,
         BidPriceTooLow,
+        SeedAlreadyCommitted,
+        SeedNotCommitted,
+        RevealTooEarly,
+        SeedMismatch,
+        KittyOnSale,
+        KittyOnCooldown,
+        TooManyChildren,
+        NotListedAsSire,
+        SireFeeTooHigh,
+        RevealTooLate,
+        SeedNotExpired,
+        TooManyPendingBirths,
     }
 }
@@ -11,6 +11,10 @@ mod events {
             index: u32,
             data: [u8; 16],
         },
+        KittyRequested {
+            owner: T::AccountId,
+            index: u32,
+        },
         KittyTransferred {
             from: T::AccountId,
             to: T::AccountId,
@@ -29,5 +33,29 @@ mod events {
             // USD cents
             usd_price: BalanceOf<T>,
         },
+        SeedCommitted {
+            who: T::AccountId,
+            commitment: T::Hash,
+        },
+        SeedRevealed {
+            who: T::AccountId,
+        },
+        SeedSlashed {
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SireListed {
+            index: u32,
+            fee: BalanceOf<T>,
+        },
+        SireUnlisted {
+            index: u32,
+        },
+        BredWithSire {
+            sire: u32,
+            matron: u32,
+            child: u32,
+            fee: BalanceOf<T>,
+        },
     }
 }
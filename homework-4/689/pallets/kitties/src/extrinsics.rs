@@ -6,30 +6,27 @@ mod dispatches {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
 
+        /// Pay for a new kitty, which hatches on the next seed revealed after this block.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::create())]
+        #[pallet::weight(T::WeightInfo::create(T::MaxPendingBirths::get()))]
         pub fn create(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let _value = Self::random_value(&who);
 
-            Self::mint_kitty(_value, &who)?;
+            Self::request_kitty(&who, None)?;
 
             Ok(())
         }
 
+        /// Breed two own kitties. The child hatches like a created kitty.
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::breed())]
+        #[pallet::weight(T::WeightInfo::breed(T::MaxPendingBirths::get()))]
         pub fn breed(origin: OriginFor<T>, kitty_1: u32, kitty_2: u32) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(kitty_1 != kitty_2, Error::<T>::SameKittyId);
             ensure!(Kitties::<T>::contains_key(kitty_1) && Kitties::<T>::contains_key(kitty_2), Error::<T>::InvalidKittyId);
             ensure!(KittyOwner::<T>::get(kitty_1).unwrap() == who && KittyOwner::<T>::get(kitty_2).unwrap() == who, Error::<T>::NotOwner);
 
-            let k1 = Kitties::<T>::get(kitty_1).unwrap();
-            let k2 = Kitties::<T>::get(kitty_2).unwrap();
-
-            let data = Self::breed_kitty(&who, k1.gene, k2.gene);
-            Self::mint_kitty(data, &who)?;            
+            Self::breed_from(&who, kitty_1, kitty_2)?;
 
             Ok(())
         }
@@ -86,5 +83,96 @@ mod dispatches {
             LatestQuota::<T>::put(quota);
             Ok(())
         }   
+
+        /// Commit to a seed for the randomness beacon, as the hash of the seed.
+        ///
+        /// Reserves `SeedDeposit` until the seed is revealed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::commit_seed())]
+        pub fn commit_seed(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::commit_to_seed(who, commitment)?;
+
+            Ok(())
+        }
+
+        /// Reveal the seed committed to in an earlier block, mixing it into the beacon.
+        ///
+        /// Returns the deposit and hatches the kitties requested after the commitment.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::reveal_seed(T::MaxPendingBirths::get()))]
+        pub fn reveal_seed(origin: OriginFor<T>, seed: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::reveal_to_beacon(who, seed)?;
+
+            Ok(())
+        }
+
+        /// List a kitty as a sire that other owners can breed with for `fee`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::list_sire())]
+        pub fn list_sire(origin: OriginFor<T>, kitty_id: u32, fee: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
+            ensure!(KittyOwner::<T>::get(kitty_id).unwrap() == who, Error::<T>::NotOwner);
+            ensure!(!KittiesSaleInfo::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+
+            SireListings::<T>::insert(kitty_id, fee);
+
+            Self::deposit_event(Event::SireListed { index: kitty_id, fee });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::unlist_sire())]
+        pub fn unlist_sire(origin: OriginFor<T>, kitty_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(KittyOwner::<T>::get(kitty_id) == Some(who), Error::<T>::NotOwner);
+            ensure!(SireListings::<T>::contains_key(kitty_id), Error::<T>::NotListedAsSire);
+
+            SireListings::<T>::remove(kitty_id);
+
+            Self::deposit_event(Event::SireUnlisted { index: kitty_id });
+
+            Ok(())
+        }
+
+        /// Breed an own `matron` with the listed `sire`, paying the fee of the sire's owner.
+        ///
+        /// The child goes to the caller. Fails if the fee is above `max_fee`, so a fee raised
+        /// after the call was signed is never paid.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::breed_with_sire(T::MaxPendingBirths::get()))]
+        pub fn breed_with_sire(origin: OriginFor<T>, sire: u32, matron: u32, max_fee: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(sire != matron, Error::<T>::SameKittyId);
+
+            let fee = SireListings::<T>::get(sire).ok_or(Error::<T>::NotListedAsSire)?;
+            ensure!(fee <= max_fee, Error::<T>::SireFeeTooHigh);
+            ensure!(Kitties::<T>::contains_key(matron), Error::<T>::InvalidKittyId);
+            ensure!(KittyOwner::<T>::get(matron).unwrap() == who, Error::<T>::NotOwner);
+
+            Self::breed_with_listed_sire(who, sire, matron, fee)?;
+
+            Ok(())
+        }
+
+        /// Slash the deposit of `who` for a seed not revealed within `RevealPeriod`.
+        ///
+        /// Anyone can call this once the period is over.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::expire_seed())]
+        pub fn expire_seed(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Self::expire_commitment(who)?;
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,231 @@
+//! The trait schema of kitty DNA and how it is inherited.
+//!
+//! Trait `n`, in the order body colour, pattern, eye shape, is carried by two alleles: the
+//! dominant one at byte `2 * n`, which is expressed, and the recessive one at byte `2 * n + 1`,
+//! which stays hidden. The remaining bytes are not expressed. Allele values map to variants
+//! over uneven ranges, so the higher variants of each trait are the rarer ones.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{sp_runtime::Permill, RuntimeDebug};
+use scale_info::TypeInfo;
+
+/// Index in the gene of the expressed allele of each trait.
+pub const BODY_COLOR: usize = 0;
+pub const PATTERN: usize = 2;
+pub const EYE_SHAPE: usize = 4;
+
+/// Number of traits a gene carries.
+pub const TRAITS: usize = 3;
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum BodyColor {
+    Ginger,
+    Black,
+    White,
+    Grey,
+    Blue,
+    Gold,
+}
+
+impl BodyColor {
+    pub fn from_allele(allele: u8) -> Self {
+        match allele {
+            0..=95 => Self::Ginger,
+            96..=159 => Self::Black,
+            160..=207 => Self::White,
+            208..=239 => Self::Grey,
+            240..=251 => Self::Blue,
+            252..=255 => Self::Gold,
+        }
+    }
+
+    /// Points the variant adds to the rarity of a kitty.
+    pub fn rarity(&self) -> u8 {
+        match self {
+            Self::Ginger | Self::Black => 0,
+            Self::White | Self::Grey => 1,
+            Self::Blue => 2,
+            Self::Gold => 3,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Pattern {
+    Solid,
+    Tabby,
+    Spotted,
+    Calico,
+    Marbled,
+}
+
+impl Pattern {
+    pub fn from_allele(allele: u8) -> Self {
+        match allele {
+            0..=111 => Self::Solid,
+            112..=183 => Self::Tabby,
+            184..=231 => Self::Spotted,
+            232..=251 => Self::Calico,
+            252..=255 => Self::Marbled,
+        }
+    }
+
+    /// Points the variant adds to the rarity of a kitty.
+    pub fn rarity(&self) -> u8 {
+        match self {
+            Self::Solid | Self::Tabby => 0,
+            Self::Spotted => 1,
+            Self::Calico => 2,
+            Self::Marbled => 3,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EyeShape {
+    Round,
+    Almond,
+    Slanted,
+    Wide,
+}
+
+impl EyeShape {
+    pub fn from_allele(allele: u8) -> Self {
+        match allele {
+            0..=127 => Self::Round,
+            128..=207 => Self::Almond,
+            208..=243 => Self::Slanted,
+            244..=255 => Self::Wide,
+        }
+    }
+
+    /// Points the variant adds to the rarity of a kitty.
+    pub fn rarity(&self) -> u8 {
+        match self {
+            Self::Round | Self::Almond => 0,
+            Self::Slanted => 1,
+            Self::Wide => 2,
+        }
+    }
+}
+
+/// How rare the combination of traits of a kitty is, from the sum of their rarity points.
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RarityTier {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl RarityTier {
+    pub fn from_points(points: u8) -> Self {
+        match points {
+            0 => Self::Common,
+            1..=2 => Self::Uncommon,
+            3..=4 => Self::Rare,
+            5..=6 => Self::Epic,
+            _ => Self::Legendary,
+        }
+    }
+}
+
+/// The traits a gene expresses.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KittyTraits {
+    pub body_color: BodyColor,
+    pub pattern: Pattern,
+    pub eye_shape: EyeShape,
+    pub rarity: RarityTier,
+}
+
+impl KittyTraits {
+    pub fn from_gene(gene: &[u8; 16]) -> Self {
+        let body_color = BodyColor::from_allele(gene[BODY_COLOR]);
+        let pattern = Pattern::from_allele(gene[PATTERN]);
+        let eye_shape = EyeShape::from_allele(gene[EYE_SHAPE]);
+        let points = body_color.rarity() + pattern.rarity() + eye_shape.rarity();
+
+        Self { body_color, pattern, eye_shape, rarity: RarityTier::from_points(points) }
+    }
+}
+
+/// Rarity points of `allele` as an allele of trait `index`.
+fn allele_rarity(index: usize, allele: u8) -> u8 {
+    match index {
+        0 => BodyColor::from_allele(allele).rarity(),
+        1 => Pattern::from_allele(allele).rarity(),
+        _ => EyeShape::from_allele(allele).rarity(),
+    }
+}
+
+/// Order two alleles of trait `index` as `(dominant, recessive)`.
+///
+/// Commoner variants dominate, so a rare variant only shows when both alleles carry it.
+pub fn dominance(index: usize, a: u8, b: u8) -> (u8, u8) {
+    if (allele_rarity(index, a), a) <= (allele_rarity(index, b), b) {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Put the dominant allele of every trait in front of the recessive one.
+pub fn normalize(mut gene: [u8; 16]) -> [u8; 16] {
+    for index in 0..TRAITS {
+        let (dominant, recessive) = dominance(index, gene[2 * index], gene[2 * index + 1]);
+        gene[2 * index] = dominant;
+        gene[2 * index + 1] = recessive;
+    }
+    gene
+}
+
+/// The gene of the child of `sire` and `matron`.
+///
+/// For every trait, the child inherits one of the two alleles of each parent, as picked by
+/// bits `2 * n` and `2 * n + 1` of `entropy[0]`. Each inherited allele then mutates with a
+/// probability of `mutation_rate`, rolled with a little-endian `u32` from `entropy[1..25]`, into
+/// the allele in `entropy[25..31]`. Bytes that carry no trait alternate between the sire's and
+/// the matron's.
+pub fn breed(
+    sire: &[u8; 16],
+    matron: &[u8; 16],
+    entropy: &[u8; 32],
+    mutation_rate: Permill,
+) -> [u8; 16] {
+    let threshold = mutation_rate.mul_floor(u32::MAX);
+    let mut child = [0u8; 16];
+
+    for index in 0..TRAITS {
+        let mut alleles = [0u8; 2];
+        for (parent, gene) in [sire, matron].into_iter().enumerate() {
+            let k = 2 * index + parent;
+            let pick = (entropy[0] >> k) & 1;
+            let roll = u32::from_le_bytes([
+                entropy[1 + 4 * k],
+                entropy[2 + 4 * k],
+                entropy[3 + 4 * k],
+                entropy[4 + 4 * k],
+            ]);
+
+            alleles[parent] = if roll < threshold {
+                entropy[25 + k]
+            } else {
+                gene[2 * index + pick as usize]
+            };
+        }
+
+        let (dominant, recessive) = dominance(index, alleles[0], alleles[1]);
+        child[2 * index] = dominant;
+        child[2 * index + 1] = recessive;
+    }
+    for (i, byte) in child.iter_mut().enumerate().skip(2 * TRAITS) {
+        *byte = if i % 2 == 0 { sire[i] } else { matron[i] };
+    }
+
+    child
+}
@@ -4,7 +4,7 @@ use frame_support::pallet_macros::pallet_section;
 #[pallet_section]
 mod hooks {
     use frame_support::traits::ExistenceRequirement;
-    use crate::migrations::migrate_to_v1;
+    use crate::migrations::{migrate_to_v1, migrate_to_v3};
     use frame_system::offchain::SubmitTransaction;
 
     #[cfg(feature = "try-runtime")]
@@ -13,8 +13,7 @@ mod hooks {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_runtime_upgrade() -> Weight {
-            migrate_to_v1::<T>()
-            // Weight::default()
+            migrate_to_v1::<T>().saturating_add(migrate_to_v3::<T>())
         }
 
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
@@ -42,6 +41,7 @@ mod hooks {
                     // change kitty owner
                     let prev_owner = KittyOwner::<T>::get(id).unwrap();
                     KittyOwner::<T>::set(id, Some(new_owner.clone()));
+                    SireListings::<T>::remove(id);
 
                     // reserve/unreserve funds
                     while !v.is_empty() {
@@ -3,7 +3,6 @@ use frame_support::pallet_macros::pallet_section;
 /// Define the implementation of the pallet, like helper functions.
 #[pallet_section]
 mod impls {
-    use sp_std::mem;
     use sp_io::hashing;
     use frame_support::sp_runtime::offchain::{http, Duration};
     use serde_json_core;
@@ -15,46 +14,140 @@ mod impls {
     }
             
     impl<T: Config> Pallet<T> {
-        // get a random 256.
-        pub fn random_value(who: &T::AccountId) -> [u8; 16] {
-            let nonce = frame_system::Pallet::<T>::account_nonce(&who);
-            // let nonce_u32: u32 = nonce as u32;
-            // generate a random value based on account and its nonce
-            let nonce_u32: u32 = TryInto::try_into(nonce).ok().expect("nonce is u64; qed");
-            let bytes = unsafe { mem::transmute::<u32, [u8; 4]>(nonce_u32) };
-
-            // payload.using_encoded(blake2_128)
-            hashing::blake2_128(&bytes)
+        /// Draw 16 random bytes for `birth` from `T::Randomness`.
+        ///
+        /// The owner, request block and account nonce of the birth and `subject` separate the
+        /// draws. All of them are fixed when the kitty is requested, so neither the block of the
+        /// reveal nor later transactions of the owner change what is drawn.
+        pub fn random_value(birth: &PendingBirthOf<T>, subject: &[u8]) -> [u8; 16] {
+            hashing::blake2_128(Self::random_seed(birth, subject).as_ref())
+        }
+
+        /// Draw 32 random bytes for `birth`, separated like `random_value`.
+        pub fn random_entropy(birth: &PendingBirthOf<T>, subject: &[u8]) -> [u8; 32] {
+            hashing::blake2_256(Self::random_seed(birth, subject).as_ref())
+        }
+
+        fn random_seed(birth: &PendingBirthOf<T>, subject: &[u8]) -> T::Hash {
+            let domain = (b"kitties", &birth.owner, birth.requested_at, birth.nonce, subject).encode();
+
+            T::Randomness::random(&domain).0
         }
 
-        // breed on kitty based on both paraent kitties
-        fn breed_kitty(who: &T::AccountId, kitty_1: [u8; 16], kitty_2: [u8; 16]) -> [u8; 16] {
-            let selector = Self::random_value(&who);
+        /// The gene of the child of `sire` and `matron` born from `birth`, following `genes::breed`.
+        fn breed_kitty(birth: &PendingBirthOf<T>, sire: [u8; 16], matron: [u8; 16]) -> [u8; 16] {
+            let entropy = Self::random_entropy(birth, &(b"breed", birth.index).encode());
 
-            let mut data = [0u8; 16];
-            for i in 0..kitty_1.len() {
-                // 0 choose kitty2, and 1 choose kitty1
-                data[i] = (kitty_1[i] & selector[i]) | (kitty_2[i] & !selector[i]);
+            genes::breed(&sire, &matron, &entropy, T::MutationRate::get())
+        }
+
+        /// Request a child of `sire` and `matron` for `owner` and put both parents on cooldown.
+        fn breed_from(owner: &T::AccountId, sire: u32, matron: u32) -> Result<u32, DispatchError> {
+            Self::ensure_can_breed(sire)?;
+            Self::ensure_can_breed(matron)?;
+
+            let id = Self::request_kitty(owner, Some((sire, matron)))?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            for parent in [sire, matron] {
+                KittyChildren::<T>::mutate(parent, |children| *children = children.saturating_add(1));
+                let cooldown = Self::breeding_cooldown(KittyGenerations::<T>::get(parent));
+                KittyCooldowns::<T>::insert(parent, now.saturating_add(cooldown));
             }
-            data
+
+            Ok(id)
         }
 
-        fn mint_kitty(data: [u8; 16], owner: &T::AccountId) -> DispatchResult {
+        fn breed_with_listed_sire(who: T::AccountId, sire: u32, matron: u32, fee: BalanceOf<T>) -> DispatchResult {
+            let sire_owner = KittyOwner::<T>::get(sire).ok_or(Error::<T>::InvalidKittyId)?;
+            let child = Self::breed_from(&who, sire, matron)?;
+
+            T::Currency::transfer(&who, &sire_owner, fee, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::BalanceNotEnough)?;
+
+            Self::deposit_event(Event::BredWithSire { sire, matron, child, fee });
+
+            Ok(())
+        }
+
+        fn ensure_can_breed(kitty_id: u32) -> DispatchResult {
+            ensure!(!KittiesSaleInfo::<T>::contains_key(kitty_id), Error::<T>::KittyOnSale);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let ready = KittyCooldowns::<T>::get(kitty_id).map_or(true, |ready_at| now >= ready_at);
+            ensure!(ready, Error::<T>::KittyOnCooldown);
+
+            let children = KittyChildren::<T>::get(kitty_id);
+            ensure!(children < T::MaxChildrenPerKitty::get(), Error::<T>::TooManyChildren);
+
+            Ok(())
+        }
+
+        /// Blocks a kitty of `generation` rests after breeding.
+        pub fn breeding_cooldown(generation: u32) -> BlockNumberFor<T> {
+            T::BreedingCooldown::get().saturating_mul(generation.saturating_add(1).into())
+        }
+
+        /// Reserve the cost of a kitty for `owner` and queue it to hatch on a later reveal.
+        ///
+        /// The whole queue is decoded and written back, which the calls requesting kitties pay
+        /// for as if it held `MaxPendingBirths` of them.
+        fn request_kitty(owner: &T::AccountId, parents: Option<(u32, u32)>) -> Result<u32, DispatchError> {
+            let mut births = PendingBirths::<T>::get();
+            let id = NextKittyId::<T>::get();
+            let next_id = id.checked_add(1).ok_or(Error::<T>::KittyIdOverflow)?;
+            let requested_at = frame_system::Pallet::<T>::block_number();
+            let nonce = frame_system::Pallet::<T>::account_nonce(owner);
+            births
+                .try_push(PendingBirth { index: id, owner: owner.clone(), parents, requested_at, nonce })
+                .map_err(|_| Error::<T>::TooManyPendingBirths)?;
+
             T::Currency::reserve(&owner, T::KittyCost::get()).map_err(|_| Error::<T>::BalanceNotEnough)?;
 
+            NextKittyId::<T>::set(next_id);
+            PendingBirths::<T>::put(births);
+
+            Self::deposit_event(Event::KittyRequested {
+                owner: owner.clone(),
+                index: id,
+            });
+
+            Ok(id)
+        }
+
+        /// Give a requested kitty its DNA, drawn from the beacon as it is now.
+        fn hatch_kitty(birth: PendingBirthOf<T>) {
+            let id = birth.index;
+
+            let (data, generation) = match birth.parents {
+                None => (genes::normalize(Self::random_value(&birth, &(b"create", id).encode())), 0),
+                Some((sire, matron)) => {
+                    let sire_gene = Kitties::<T>::get(sire).unwrap_or_default().gene;
+                    let matron_gene = Kitties::<T>::get(matron).unwrap_or_default().gene;
+                    let generation = KittyGenerations::<T>::get(sire)
+                        .max(KittyGenerations::<T>::get(matron))
+                        .saturating_add(1);
+                    KittyParents::<T>::insert(id, (sire, matron));
+
+                    (Self::breed_kitty(&birth, sire_gene, matron_gene), generation)
+                }
+            };
+
             let kittie = Kitty { gene: data, price: 0 };
-            let id = NextKittyId::<T>::get();
             Kitties::<T>::insert(id, kittie);
-            NextKittyId::<T>::set(id.checked_add(1).ok_or(Error::<T>::KittyIdOverflow)?);
-            KittyOwner::<T>::insert(id, owner);
+            KittyOwner::<T>::insert(id, &birth.owner);
+            KittyGenerations::<T>::insert(id, generation);
 
             Self::deposit_event(Event::KittyCreated {
-                creator: owner.clone(),
+                creator: birth.owner,
                 index: id,
                 data: data,
             });
+        }
 
-            Ok(())
+        /// The traits the DNA of `kitty_id` expresses, if there is such a kitty.
+        pub fn kitty_traits(kitty_id: u32) -> Option<KittyTraits> {
+            Kitties::<T>::get(kitty_id).map(|kitty| KittyTraits::from_gene(&kitty.gene))
         }
 
         fn transfer_kitty(from: T::AccountId, to: T::AccountId, kitty_id: u32) -> DispatchResult {
@@ -62,6 +155,7 @@ mod impls {
             T::Currency::unreserve(&from, T::KittyCost::get());
 
             KittyOwner::<T>::set(kitty_id, Some(to.clone()));
+            SireListings::<T>::remove(kitty_id);
 
             Self::deposit_event(Event::KittyTransferred {
                 from: from,
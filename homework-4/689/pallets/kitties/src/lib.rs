@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use frame_support::pallet_macros::import_section;
 use scale_info::prelude::format;
+pub use genes::KittyTraits;
 pub use pallet::*;
 
 #[cfg(test)]
@@ -11,6 +12,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod genes;
 pub mod weights;
 pub use weights::*;
 
@@ -22,6 +24,7 @@ mod genesis;
 mod hooks;
 mod impls;
 mod migrations;
+mod randomness;
 mod validate;
 
 
@@ -34,6 +37,7 @@ mod validate;
 #[import_section(impls::impls)]
 #[import_section(genesis::genesis)]
 #[import_section(validate::validate)]
+#[import_section(randomness::randomness)]
 /// Set the pallet at dev mode for quick PoC.
 #[frame_support::pallet]
 pub mod pallet {
@@ -49,7 +53,7 @@ pub mod pallet {
 
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
     
-    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[derive(Encode, Decode, Clone, Default, TypeInfo, Serialize, Deserialize, MaxEncodedLen)]
     pub struct Kitty {
@@ -57,6 +61,27 @@ pub mod pallet {
         pub price: u64,
     }
 
+    /// A kitty that is paid for but not born yet.
+    ///
+    /// It hatches on the first seed another account reveals after `requested_at` that was
+    /// committed to before it, so nobody knows its DNA when asking for it.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct PendingBirth<AccountId, BlockNumber, Nonce> {
+        pub index: u32,
+        pub owner: AccountId,
+        /// `(sire, matron)` for bred kitties, `None` for created ones.
+        pub parents: Option<(u32, u32)>,
+        pub requested_at: BlockNumber,
+        /// Account nonce of `owner` when it asked for the kitty.
+        pub nonce: Nonce,
+    }
+
+    pub type PendingBirthOf<T> = PendingBirth<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Nonce,
+    >;
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
@@ -67,6 +92,27 @@ pub mod pallet {
     #[pallet::storage]
     pub type Kitties<T> = StorageMap<_, Blake2_128Concat, u32, Kitty>;
 
+    /// Generation of each kitty: 0 for created kitties, one more than the older parent for
+    /// bred ones.
+    #[pallet::storage]
+    pub type KittyGenerations<T> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+
+    /// Parents of each bred kitty, as `(sire, matron)`.
+    #[pallet::storage]
+    pub type KittyParents<T> = StorageMap<_, Blake2_128Concat, u32, (u32, u32)>;
+
+    /// Number of children each kitty has bred.
+    #[pallet::storage]
+    pub type KittyChildren<T> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+
+    /// First block at which each kitty that has bred can breed again.
+    #[pallet::storage]
+    pub type KittyCooldowns<T: Config> = StorageMap<_, Blake2_128Concat, u32, BlockNumberFor<T>>;
+
+    /// Kitties listed as sires, with the fee their owner charges for each breeding.
+    #[pallet::storage]
+    pub type SireListings<T: Config> = StorageMap<_, Blake2_128Concat, u32, BalanceOf<T>>;
+
     #[pallet::storage]
     pub type KittyOwner<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
 
@@ -79,4 +125,18 @@ pub mod pallet {
 
     #[pallet::storage]
     pub type LatestQuota<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Seeds committed to by each account, with the block of the commitment.
+    #[pallet::storage]
+    pub type SeedCommitments<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (T::Hash, BlockNumberFor<T>)>;
+
+    /// The beacon of `RevealedRandomness`, with the block of the last reveal.
+    #[pallet::storage]
+    pub type RandomSeed<T: Config> = StorageValue<_, (T::Hash, BlockNumberFor<T>), ValueQuery>;
+
+    /// Kitties waiting to hatch, oldest request first.
+    #[pallet::storage]
+    pub type PendingBirths<T: Config> =
+        StorageValue<_, BoundedVec<PendingBirthOf<T>, T::MaxPendingBirths>, ValueQuery>;
 }
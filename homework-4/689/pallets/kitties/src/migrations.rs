@@ -37,3 +37,21 @@ pub fn migrate_to_v1<T: Config>() -> Weight {
         Weight::zero()
     }
 }
+
+/// Put the dominant allele of every trait of existing kitties in front, as `genes::normalize`
+/// does for new ones, so that `KittyTraits::from_gene` reads the trait their genes express.
+pub fn migrate_to_v3<T: Config>() -> Weight {
+    let on_chain: StorageVersion = Pallet::<T>::on_chain_storage_version();
+    if on_chain < 3 {
+        let mut count = 0u64;
+        Kitties::<T>::translate_values::<Kitty, _>(|kitty| {
+            count += 1;
+            Some(Kitty { gene: crate::genes::normalize(kitty.gene), ..kitty })
+        });
+        StorageVersion::new(3).put::<Pallet<T>>();
+        log::info!("normalized the genes of {} kitties", count);
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    } else {
+        Weight::zero()
+    }
+}
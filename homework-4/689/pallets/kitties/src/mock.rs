@@ -1,14 +1,14 @@
 use crate as pallet_kitties;
-use frame_support::traits::Hooks;
+use frame_support::traits::{Hooks, Randomness};
 use frame_support::{
-    derive_impl,
+    derive_impl, parameter_types,
     traits::{ConstU16, ConstU32, ConstU64, ConstU128},
     weights::Weight,
 };
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    BuildStorage, Permill,
 };
 use pallet_balances;
 
@@ -22,7 +22,6 @@ frame_support::construct_runtime!(
         System: frame_system,
         Kitties: pallet_kitties,
         Balances: pallet_balances,
-        Random: pallet_insecure_randomness_collective_flip,
     }
 );
 
@@ -72,14 +71,31 @@ impl pallet_balances::Config for Test {
 impl pallet_kitties::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
-    type Randomness = Random;
+    type Randomness = MockRandomness;
 
     type Currency = Balances;
     type BidMargin = ConstU128<100>;
     type KittyCost = ConstU128<500>;    
+    type MutationRate = MutationRate;
+    type BreedingCooldown = ConstU64<5>;
+    type MaxChildrenPerKitty = ConstU32<3>;
+    type SeedDeposit = ConstU128<100>;
+    type RevealPeriod = ConstU64<20>;
+    type MaxPendingBirths = ConstU32<4>;
 }
 
-impl pallet_insecure_randomness_collective_flip::Config for Test {}
+parameter_types! {
+    pub const MutationRate: Permill = Permill::from_percent(1);
+}
+
+/// Deterministic randomness: the hash of the subject, so tests can predict every draw.
+pub struct MockRandomness;
+
+impl Randomness<H256, u64> for MockRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        (BlakeTwo256::hash(subject), System::block_number())
+    }
+}
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -90,7 +106,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 
     // This will cause some initial issuance
     pallet_balances::GenesisConfig::<Test> {
-        balances: vec![(1, 5_000), (2, 5_000), (3, 5_000)],
+        balances: vec![(1, 5_000), (2, 5_000), (3, 5_000), (4, 5_000)],
     }
     .assimilate_storage(&mut storage)
     .ok();
@@ -0,0 +1,94 @@
+use frame_support::pallet_macros::pallet_section;
+
+/// Define the commit-reveal randomness beacon of the pallet.
+#[pallet_section]
+mod randomness {
+    use frame_support::sp_runtime::traits::{Hash, Saturating};
+
+    /// A [`Randomness`] provider fed by the seeds accounts reveal with `reveal_seed`.
+    ///
+    /// Every seed is committed to one block or more before it is revealed, so whoever commits
+    /// cannot pick it to suit what is drawn later. The beacon is only as unpredictable as its
+    /// most recent reveal; the block number returned is the one it last changed at.
+    ///
+    /// The beacon itself is public, so the pallet only draws from it for kitties requested
+    /// before the reveal that last changed it. A revealer knows the beacon its reveal leads to
+    /// from the moment it commits, so its own kitties wait for someone else's reveal; kitties of
+    /// other accounts it controls are not told apart. A revealer also sees what its reveal would
+    /// hatch and may withhold it, at the cost of `SeedDeposit`; the kitties then wait for the
+    /// next reveal.
+    pub struct RevealedRandomness<T>(PhantomData<T>);
+
+    impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for RevealedRandomness<T> {
+        fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            let (seed, block) = RandomSeed::<T>::get();
+            (T::Hashing::hash_of(&(seed, subject)), block)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn commit_to_seed(who: T::AccountId, commitment: T::Hash) -> DispatchResult {
+            ensure!(!SeedCommitments::<T>::contains_key(&who), Error::<T>::SeedAlreadyCommitted);
+            T::Currency::reserve(&who, T::SeedDeposit::get()).map_err(|_| Error::<T>::BalanceNotEnough)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            SeedCommitments::<T>::insert(&who, (commitment, now));
+
+            Self::deposit_event(Event::SeedCommitted { who, commitment });
+
+            Ok(())
+        }
+
+        fn reveal_to_beacon(who: T::AccountId, seed: [u8; 32]) -> DispatchResult {
+            let (commitment, committed_at) =
+                SeedCommitments::<T>::get(&who).ok_or(Error::<T>::SeedNotCommitted)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now > committed_at, Error::<T>::RevealTooEarly);
+            ensure!(now <= committed_at.saturating_add(T::RevealPeriod::get()), Error::<T>::RevealTooLate);
+            ensure!(T::Hashing::hash(&seed) == commitment, Error::<T>::SeedMismatch);
+
+            SeedCommitments::<T>::remove(&who);
+            T::Currency::unreserve(&who, T::SeedDeposit::get());
+            RandomSeed::<T>::mutate(|(beacon, block)| {
+                *beacon = T::Hashing::hash_of(&(*beacon, &who, seed));
+                *block = now;
+            });
+
+            Self::deposit_event(Event::SeedRevealed { who });
+
+            Self::hatch_births(&who, committed_at, now);
+
+            Ok(())
+        }
+
+        /// Slash the deposit of a seed `who` did not reveal in time and drop the commitment.
+        fn expire_commitment(who: T::AccountId) -> DispatchResult {
+            let (_, committed_at) = SeedCommitments::<T>::get(&who).ok_or(Error::<T>::SeedNotCommitted)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now > committed_at.saturating_add(T::RevealPeriod::get()), Error::<T>::SeedNotExpired);
+
+            SeedCommitments::<T>::remove(&who);
+            let (_, unslashed) = T::Currency::slash_reserved(&who, T::SeedDeposit::get());
+            let amount = T::SeedDeposit::get().saturating_sub(unslashed);
+
+            Self::deposit_event(Event::SeedSlashed { who, amount });
+
+            Ok(())
+        }
+
+        /// Hatch the kitties of accounts other than `revealer` requested after `committed_at` and
+        /// before `now`, once `revealer` reveals a seed committed at `committed_at` at `now`.
+        ///
+        /// Their owners had no say in the seed, and could not see it before asking for them.
+        fn hatch_births(revealer: &T::AccountId, committed_at: BlockNumberFor<T>, now: BlockNumberFor<T>) {
+            let (ready, waiting): (Vec<_>, Vec<_>) = PendingBirths::<T>::get().into_iter().partition(|birth| {
+                birth.owner != *revealer && committed_at < birth.requested_at && birth.requested_at < now
+            });
+            PendingBirths::<T>::put(BoundedVec::truncate_from(waiting));
+
+            ready.into_iter().for_each(Self::hatch_kitty);
+        }
+    }
+}
@@ -2,6 +2,32 @@ use crate::{mock::*, Error, Event};
 use frame_support::{assert_noop, assert_ok};
 use crate::{Kitties as KittiesArray, NextKittyId, KittyOwner, KittiesBid, KittiesSaleInfo};
 use crate::pallet;
+use crate::{RevealedRandomness, SeedCommitments, KittyGenerations};
+use crate::{KittyChildren, KittyCooldowns, KittyParents, PendingBirth, PendingBirths, SireListings};
+use crate::genes::{self, BodyColor, EyeShape, KittyTraits, Pattern, RarityTier};
+use codec::Encode;
+use frame_support::traits::Randomness;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::Permill;
+
+/// The account feeding the beacon in tests.
+const REVEALER: u64 = 4;
+
+/// Commit the revealer to a seed and move to the next block, so kitties requested from there on
+/// hatch when it is revealed.
+fn commit_next_seed() {
+    let seed = [System::block_number() as u8; 32];
+    assert_ok!(Kitties::commit_seed(RuntimeOrigin::signed(REVEALER), BlakeTwo256::hash(&seed)));
+    run_to_block(System::block_number() + 1);
+}
+
+/// Reveal the seed of the revealer in the next block, hatching the kitties requested since the
+/// commitment.
+fn hatch() {
+    let (_, committed_at) = SeedCommitments::<Test>::get(REVEALER).unwrap();
+    run_to_block(System::block_number() + 1);
+    assert_ok!(Kitties::reveal_seed(RuntimeOrigin::signed(REVEALER), [committed_at as u8; 32]));
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -24,43 +50,57 @@ fn ok_all() {
         // log::error!("{}", charlie_balance);
 
         // create two kitties
+        commit_next_seed();
         assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
 
         assert_eq!(NextKittyId::<Test>::get(), 1);
-        assert_eq!(KittiesArray::<Test>::contains_key(0), true);
-        assert_eq!(KittyOwner::<Test>::contains_key(0), true);
-        assert_eq!(KittyOwner::<Test>::get(0).unwrap(), alice);
+        assert_eq!(KittiesArray::<Test>::contains_key(0), false);
         assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), alice_balance - 500);
-        System::assert_has_event(Event::<Test>::KittyCreated {
-                creator: alice,
-                index: 0,
-                data: Kitties::random_value(&alice),
-            }.into());
+        System::assert_has_event(Event::<Test>::KittyRequested { owner: alice, index: 0 }.into());
 
         assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
         assert_eq!(NextKittyId::<Test>::get(), 2);
+        assert_eq!(PendingBirths::<Test>::get().len(), 2);
+        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), alice_balance - 1000);
+
+        let births = PendingBirths::<Test>::get();
+        hatch();
+        assert_eq!(PendingBirths::<Test>::get().is_empty(), true);
+        assert_eq!(KittiesArray::<Test>::contains_key(0), true);
+        assert_eq!(KittyOwner::<Test>::get(0).unwrap(), alice);
         assert_eq!(KittiesArray::<Test>::contains_key(1), true);
-        assert_eq!(KittyOwner::<Test>::contains_key(1), true);
         assert_eq!(KittyOwner::<Test>::get(1).unwrap(), alice);
-        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), alice_balance - 1000);
+        System::assert_has_event(Event::<Test>::KittyCreated {
+            creator: alice,
+            index: 0,
+            data: genes::normalize(Kitties::random_value(&births[0], &(b"create", 0u32).encode())),
+        }.into());
         System::assert_has_event(Event::<Test>::KittyCreated {
             creator: alice,
             index: 1,
-            data: Kitties::random_value(&alice),
+            data: genes::normalize(Kitties::random_value(&births[1], &(b"create", 1u32).encode())),
         }.into());
 
         // breed two kitties
+        commit_next_seed();
         assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 1));
 
         assert_eq!(NextKittyId::<Test>::get(), 3);
+        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), alice_balance - 1500);
+
+        let births = PendingBirths::<Test>::get();
+        hatch();
         assert_eq!(KittiesArray::<Test>::contains_key(2), true);
-        assert_eq!(KittyOwner::<Test>::contains_key(2), true);
         assert_eq!(KittyOwner::<Test>::get(2).unwrap(), alice);
-        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), alice_balance - 1500);
         System::assert_has_event(Event::<Test>::KittyCreated {
             creator: alice,
             index: 2,
-            data: Kitties::random_value(&alice),
+            data: genes::breed(
+                &KittiesArray::<Test>::get(0).unwrap().gene,
+                &KittiesArray::<Test>::get(1).unwrap().gene,
+                &Kitties::random_entropy(&births[0], &(b"breed", 2u32).encode()),
+                MutationRate::get(),
+            ),
         }.into());
 
         // transfer a kitty
@@ -78,10 +118,8 @@ fn ok_all() {
             index: 0,
         }.into());
 
-        run_to_block(1);
-
         // put a kitty on sale
-        let until_block = 5;
+        let until_block = 10;
         assert_ok!(Kitties::sale(RuntimeOrigin::signed(alice), 1, 1000, until_block));
 
         assert_eq!(KittiesBid::<Test>::contains_key(1), true);
@@ -114,3 +152,434 @@ fn ok_all() {
 
     });
 }
+
+#[test]
+fn random_value_is_separated_by_request_and_subject() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        let birth = PendingBirth { index: 0, owner: alice, parents: None, requested_at: 1, nonce: 0 };
+
+        let value = Kitties::random_value(&birth, b"create");
+        assert_eq!(value, Kitties::random_value(&birth, b"create"));
+        assert_ne!(value, Kitties::random_value(&birth, b"breed"));
+        assert_ne!(value, Kitties::random_value(&PendingBirth { owner: bob, ..birth.clone() }, b"create"));
+        assert_ne!(value, Kitties::random_value(&PendingBirth { requested_at: 2, ..birth.clone() }, b"create"));
+        assert_ne!(value, Kitties::random_value(&PendingBirth { nonce: 1, ..birth.clone() }, b"create"));
+
+        // Neither the block of the hatch nor the owner's later transactions change the draw.
+        run_to_block(2);
+        frame_system::Pallet::<Test>::inc_account_nonce(alice);
+        assert_eq!(value, Kitties::random_value(&birth, b"create"));
+    });
+}
+
+#[test]
+fn seed_commit_and_reveal() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let seed = [7u8; 32];
+        let commitment = BlakeTwo256::hash(&seed);
+        let (before, _) = RevealedRandomness::<Test>::random(b"subject");
+
+        assert_ok!(Kitties::commit_seed(RuntimeOrigin::signed(alice), commitment));
+        System::assert_has_event(Event::<Test>::SeedCommitted { who: alice, commitment }.into());
+        assert_eq!(<Test as pallet::Config>::Currency::reserved_balance(&alice), 100);
+        assert_noop!(
+            Kitties::commit_seed(RuntimeOrigin::signed(alice), commitment),
+            Error::<Test>::SeedAlreadyCommitted
+        );
+
+        // A seed cannot be revealed in the block it was committed in.
+        assert_noop!(Kitties::reveal_seed(RuntimeOrigin::signed(alice), seed), Error::<Test>::RevealTooEarly);
+
+        run_to_block(2);
+        assert_noop!(Kitties::reveal_seed(RuntimeOrigin::signed(alice), [8u8; 32]), Error::<Test>::SeedMismatch);
+        assert_noop!(Kitties::reveal_seed(RuntimeOrigin::signed(2), seed), Error::<Test>::SeedNotCommitted);
+
+        assert_ok!(Kitties::reveal_seed(RuntimeOrigin::signed(alice), seed));
+        System::assert_has_event(Event::<Test>::SeedRevealed { who: alice }.into());
+        assert_eq!(SeedCommitments::<Test>::contains_key(alice), false);
+        assert_eq!(<Test as pallet::Config>::Currency::reserved_balance(&alice), 0);
+
+        let (after, known_since) = RevealedRandomness::<Test>::random(b"subject");
+        assert_ne!(before, after);
+        assert_eq!(known_since, 2);
+    });
+}
+
+#[test]
+fn unrevealed_seed_is_slashed() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        let seed = [7u8; 32];
+
+        assert_ok!(Kitties::commit_seed(RuntimeOrigin::signed(alice), BlakeTwo256::hash(&seed)));
+        assert_noop!(Kitties::expire_seed(RuntimeOrigin::signed(bob), bob), Error::<Test>::SeedNotCommitted);
+
+        // The seed can be revealed for 20 blocks after the commitment.
+        run_to_block(21);
+        assert_noop!(Kitties::expire_seed(RuntimeOrigin::signed(bob), alice), Error::<Test>::SeedNotExpired);
+
+        run_to_block(22);
+        assert_noop!(Kitties::reveal_seed(RuntimeOrigin::signed(alice), seed), Error::<Test>::RevealTooLate);
+
+        assert_ok!(Kitties::expire_seed(RuntimeOrigin::signed(bob), alice));
+        System::assert_has_event(Event::<Test>::SeedSlashed { who: alice, amount: 100 }.into());
+        assert_eq!(SeedCommitments::<Test>::contains_key(alice), false);
+        assert_eq!(<Test as pallet::Config>::Currency::reserved_balance(&alice), 0);
+        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), 4_900);
+
+        assert_noop!(Kitties::expire_seed(RuntimeOrigin::signed(bob), alice), Error::<Test>::SeedNotCommitted);
+    });
+}
+
+#[test]
+fn kitty_hatches_on_a_later_reveal_of_an_earlier_seed() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        let charlie = 3;
+
+        assert_ok!(Kitties::commit_seed(RuntimeOrigin::signed(charlie), BlakeTwo256::hash(&[3u8; 32])));
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_eq!(Kitties::kitty_traits(0), None);
+
+        // A seed revealed in the block of the request does not hatch it.
+        assert_ok!(Kitties::reveal_seed(RuntimeOrigin::signed(charlie), [3u8; 32]));
+        assert_eq!(KittiesArray::<Test>::contains_key(0), false);
+
+        // Neither does a seed committed to in or after that block.
+        assert_ok!(Kitties::commit_seed(RuntimeOrigin::signed(bob), BlakeTwo256::hash(&[9u8; 32])));
+        run_to_block(3);
+        assert_ok!(Kitties::reveal_seed(RuntimeOrigin::signed(bob), [9u8; 32]));
+        assert_eq!(KittiesArray::<Test>::contains_key(0), false);
+        assert_eq!(PendingBirths::<Test>::get().len(), 1);
+
+        hatch();
+        assert_eq!(KittyOwner::<Test>::get(0), Some(alice));
+        assert_eq!(PendingBirths::<Test>::get().is_empty(), true);
+
+        // Only so many kitties can wait to hatch.
+        for _ in 0..4 {
+            assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        }
+        assert_noop!(Kitties::create(RuntimeOrigin::signed(alice)), Error::<Test>::TooManyPendingBirths);
+    });
+}
+
+#[test]
+fn revealer_kitty_waits_for_another_reveal() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+
+        // Alice knows what her own seed will make of the beacon.
+        assert_ok!(Kitties::commit_seed(RuntimeOrigin::signed(alice), BlakeTwo256::hash(&[1u8; 32])));
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(bob)));
+
+        run_to_block(3);
+        assert_ok!(Kitties::reveal_seed(RuntimeOrigin::signed(alice), [1u8; 32]));
+        assert_eq!(KittiesArray::<Test>::contains_key(0), false);
+        assert_eq!(KittyOwner::<Test>::get(1), Some(bob));
+
+        hatch();
+        assert_eq!(KittyOwner::<Test>::get(0), Some(alice));
+        assert_eq!(PendingBirths::<Test>::get().is_empty(), true);
+    });
+}
+
+#[test]
+fn gene_decodes_into_traits() {
+    let mut gene = [0u8; 16];
+    assert_eq!(KittyTraits::from_gene(&gene), KittyTraits {
+        body_color: BodyColor::Ginger,
+        pattern: Pattern::Solid,
+        eye_shape: EyeShape::Round,
+        rarity: RarityTier::Common,
+    });
+
+    // Only the expressed alleles count.
+    gene[1] = 255;
+    gene[15] = 255;
+    assert_eq!(KittyTraits::from_gene(&gene).rarity, RarityTier::Common);
+
+    gene[0] = 200;
+    gene[2] = 190;
+    gene[4] = 130;
+    assert_eq!(KittyTraits::from_gene(&gene), KittyTraits {
+        body_color: BodyColor::White,
+        pattern: Pattern::Spotted,
+        eye_shape: EyeShape::Almond,
+        rarity: RarityTier::Uncommon,
+    });
+
+    gene[0] = 255;
+    gene[2] = 255;
+    gene[4] = 255;
+    assert_eq!(KittyTraits::from_gene(&gene), KittyTraits {
+        body_color: BodyColor::Gold,
+        pattern: Pattern::Marbled,
+        eye_shape: EyeShape::Wide,
+        rarity: RarityTier::Legendary,
+    });
+}
+
+#[test]
+fn breed_records_generation() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        hatch();
+        assert_eq!(KittyGenerations::<Test>::get(0), 0);
+
+        commit_next_seed();
+        assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 1));
+        hatch();
+        assert_eq!(KittyGenerations::<Test>::get(2), 1);
+
+        // The older parent sets the generation.
+        run_to_block(8);
+        commit_next_seed();
+        assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 2, 0));
+        hatch();
+        assert_eq!(KittyGenerations::<Test>::get(3), 2);
+
+        let gene = KittiesArray::<Test>::get(3).unwrap().gene;
+        assert_eq!(Kitties::kitty_traits(3), Some(KittyTraits::from_gene(&gene)));
+        assert_eq!(Kitties::kitty_traits(4), None);
+    });
+}
+
+#[test]
+fn dominant_allele_is_expressed() {
+    // Ginger dominates gold, whichever order they come in.
+    assert_eq!(genes::dominance(0, 255, 10), (10, 255));
+    assert_eq!(genes::dominance(0, 10, 255), (10, 255));
+
+    let mut gene = [0u8; 16];
+    gene[0] = 255;
+    gene[1] = 10;
+    gene[4] = 250;
+    gene[5] = 250;
+    let gene = genes::normalize(gene);
+    assert_eq!((gene[0], gene[1]), (10, 255));
+    assert_eq!(KittyTraits::from_gene(&gene).body_color, BodyColor::Ginger);
+    assert_eq!(KittyTraits::from_gene(&gene).eye_shape, EyeShape::Wide);
+}
+
+#[test]
+fn breed_inherits_one_allele_from_each_parent() {
+    // Both parents are ginger carrying a hidden gold allele.
+    let mut carrier = [0u8; 16];
+    carrier[0] = 10;
+    carrier[1] = 255;
+
+    // Picking the dominant allele of either parent keeps the child ginger.
+    let mut entropy = [0u8; 32];
+    let child = genes::breed(&carrier, &carrier, &entropy, Permill::zero());
+    assert_eq!((child[0], child[1]), (10, 10));
+    assert_eq!(KittyTraits::from_gene(&child).body_color, BodyColor::Ginger);
+
+    // One recessive allele stays hidden.
+    entropy[0] = 0b01;
+    let child = genes::breed(&carrier, &carrier, &entropy, Permill::zero());
+    assert_eq!((child[0], child[1]), (10, 255));
+    assert_eq!(KittyTraits::from_gene(&child).body_color, BodyColor::Ginger);
+
+    // Two recessive alleles express the rare variant.
+    entropy[0] = 0b11;
+    let child = genes::breed(&carrier, &carrier, &entropy, Permill::zero());
+    assert_eq!((child[0], child[1]), (255, 255));
+    assert_eq!(KittyTraits::from_gene(&child).body_color, BodyColor::Gold);
+
+    // Bytes without traits alternate between the parents.
+    let child = genes::breed(&[1u8; 16], &[2u8; 16], &entropy, Permill::zero());
+    assert_eq!(child[6..], [1, 2, 1, 2, 1, 2, 1, 2, 1, 2]);
+}
+
+#[test]
+fn breed_mutates_at_mutation_rate() {
+    let mut entropy = [0u8; 32];
+    for (k, allele) in entropy[25..31].iter_mut().enumerate() {
+        *allele = 250 + k as u8;
+    }
+
+    // A roll of zero is below any non-zero rate.
+    let child = genes::breed(&[0u8; 16], &[0u8; 16], &entropy, Permill::from_parts(1));
+    assert_eq!(child[..6], [250, 251, 252, 253, 254, 255]);
+    let child = genes::breed(&[0u8; 16], &[0u8; 16], &entropy, Permill::zero());
+    assert_eq!(child, [0u8; 16]);
+
+    // Rolls above the rate keep the inherited alleles.
+    entropy[1..25].copy_from_slice(&[u8::MAX; 24]);
+    let child = genes::breed(&[0u8; 16], &[0u8; 16], &entropy, MutationRate::get());
+    assert_eq!(child, [0u8; 16]);
+}
+
+#[test]
+fn breed_records_parents_and_cooldowns() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        hatch();
+
+        // Parents are put on cooldown when the child is requested.
+        commit_next_seed();
+        assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 1));
+        assert_eq!(KittyChildren::<Test>::get(0), 1);
+        assert_eq!(KittyChildren::<Test>::get(1), 1);
+        assert_eq!(KittyCooldowns::<Test>::get(0), Some(9));
+        assert_eq!(KittyParents::<Test>::get(2), None);
+
+        hatch();
+        assert_eq!(KittyParents::<Test>::get(2), Some((0, 1)));
+        assert_eq!(KittyParents::<Test>::get(0), None);
+
+        // The same pair cannot breed again until both parents have rested.
+        assert_noop!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 1), Error::<Test>::KittyOnCooldown);
+        assert_noop!(Kitties::breed(RuntimeOrigin::signed(alice), 2, 1), Error::<Test>::KittyOnCooldown);
+
+        run_to_block(8);
+        commit_next_seed();
+        assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 1, 0));
+        hatch();
+        assert_eq!(KittyParents::<Test>::get(3), Some((1, 0)));
+
+        // Later generations rest longer.
+        assert_eq!(Kitties::breeding_cooldown(1), 10);
+        commit_next_seed();
+        assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 2, 3));
+        assert_eq!(KittyCooldowns::<Test>::get(2), Some(21));
+        hatch();
+        assert_eq!(KittyGenerations::<Test>::get(4), 2);
+    });
+}
+
+#[test]
+fn breed_rejects_kitties_on_sale_or_with_too_many_children() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        hatch();
+        assert_ok!(Kitties::sale(RuntimeOrigin::signed(alice), 2, 1000, 5));
+        assert_noop!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 2), Error::<Test>::KittyOnSale);
+
+        commit_next_seed();
+        for block in [4, 9, 14] {
+            run_to_block(block);
+            assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 1));
+        }
+        assert_eq!(KittyChildren::<Test>::get(0), 3);
+
+        run_to_block(19);
+        assert_noop!(Kitties::breed(RuntimeOrigin::signed(alice), 0, 1), Error::<Test>::TooManyChildren);
+
+        // The sale has ended.
+        hatch();
+        assert_ok!(Kitties::breed(RuntimeOrigin::signed(alice), 2, 5));
+    });
+}
+
+#[test]
+fn breed_with_listed_sire() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(bob)));
+        hatch();
+        let alice_balance = <Test as pallet::Config>::Currency::free_balance(&alice);
+        let bob_balance = <Test as pallet::Config>::Currency::free_balance(&bob);
+
+        assert_noop!(Kitties::breed_with_sire(RuntimeOrigin::signed(bob), 0, 1, 300), Error::<Test>::NotListedAsSire);
+        assert_noop!(Kitties::list_sire(RuntimeOrigin::signed(bob), 0, 200), Error::<Test>::NotOwner);
+        assert_ok!(Kitties::list_sire(RuntimeOrigin::signed(alice), 0, 200));
+        assert_eq!(SireListings::<Test>::get(0), Some(200));
+        System::assert_has_event(Event::<Test>::SireListed { index: 0, fee: 200 }.into());
+
+        assert_noop!(Kitties::breed_with_sire(RuntimeOrigin::signed(bob), 0, 1, 100), Error::<Test>::SireFeeTooHigh);
+        assert_noop!(Kitties::breed_with_sire(RuntimeOrigin::signed(alice), 0, 1, 300), Error::<Test>::NotOwner);
+
+        // The fee goes to the owner of the sire and the child to the caller.
+        commit_next_seed();
+        assert_ok!(Kitties::breed_with_sire(RuntimeOrigin::signed(bob), 0, 1, 300));
+        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&alice), alice_balance + 200);
+        assert_eq!(<Test as pallet::Config>::Currency::free_balance(&bob), bob_balance - 200 - 500);
+        System::assert_has_event(Event::<Test>::BredWithSire { sire: 0, matron: 1, child: 2, fee: 200 }.into());
+
+        hatch();
+        assert_eq!(KittyOwner::<Test>::get(0), Some(alice));
+        assert_eq!(KittyOwner::<Test>::get(2), Some(bob));
+        assert_eq!(KittyParents::<Test>::get(2), Some((0, 1)));
+
+        // The sire rests like any other parent.
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(bob)));
+        hatch();
+        assert_noop!(Kitties::breed_with_sire(RuntimeOrigin::signed(bob), 0, 3, 300), Error::<Test>::KittyOnCooldown);
+
+        assert_noop!(Kitties::unlist_sire(RuntimeOrigin::signed(bob), 0), Error::<Test>::NotOwner);
+        assert_ok!(Kitties::unlist_sire(RuntimeOrigin::signed(alice), 0));
+        assert_eq!(SireListings::<Test>::contains_key(0), false);
+        System::assert_has_event(Event::<Test>::SireUnlisted { index: 0 }.into());
+        assert_noop!(Kitties::unlist_sire(RuntimeOrigin::signed(alice), 0), Error::<Test>::NotListedAsSire);
+    });
+}
+
+#[test]
+fn transfer_ends_sire_listing() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+
+        commit_next_seed();
+        assert_ok!(Kitties::create(RuntimeOrigin::signed(alice)));
+        hatch();
+        assert_ok!(Kitties::list_sire(RuntimeOrigin::signed(alice), 0, 200));
+        assert_ok!(Kitties::transfer(RuntimeOrigin::signed(alice), bob, 0));
+        assert_eq!(SireListings::<Test>::contains_key(0), false);
+
+        // Kitties on sale cannot be listed.
+        assert_ok!(Kitties::sale(RuntimeOrigin::signed(bob), 0, 1000, 5));
+        assert_noop!(Kitties::list_sire(RuntimeOrigin::signed(bob), 0, 200), Error::<Test>::KittyOnSale);
+    });
+}
+
+#[test]
+fn migration_to_v3_normalizes_genes() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        // A gold allele in front of a ginger one, as kitties born before dominance carried.
+        let mut gene = [0u8; 16];
+        gene[0] = 255;
+        gene[1] = 10;
+        KittiesArray::<Test>::insert(0, pallet::Kitty { gene, price: 7 });
+        StorageVersion::new(2).put::<Kitties>();
+
+        crate::migrations::migrate_to_v3::<Test>();
+
+        let kitty = KittiesArray::<Test>::get(0).unwrap();
+        assert_eq!((kitty.gene[0], kitty.gene[1]), (10, 255));
+        assert_eq!(kitty.price, 7);
+        assert_eq!(Kitties::kitty_traits(0).unwrap().body_color, BodyColor::Ginger);
+        assert_eq!(Kitties::on_chain_storage_version(), StorageVersion::new(3));
+    });
+}
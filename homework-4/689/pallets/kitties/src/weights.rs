@@ -1,29 +1,17 @@
-
-//! Autogenerated weights for `pallet_kitties`
+//! Weights for `pallet_kitties`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-09-24, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `qdeMacBook-Pro.lan`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/solochain-template-node
-// benchmark
-// pallet
-// --wasm-execution=compiled
-// --pallet
-// pallet_kitties
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// pallets/kitties/src/weights.rs
-// --template
-// .maintain/frame-weight-template.hbs
+//! Estimates kept up to date by hand. A benchmark CLI 32.0.0 run on 2024-09-24 measured the
+//! first five calls, `create` to `bid`. The seed, sire and hatching calls came later, and
+//! `create` and `breed` no longer mint on the spot but queue the kitty, paying for every
+//! birth already queued. Their numbers were written by hand from the storage each call touches,
+//! without re-running the benchmarks. Re-run them and overwrite this file before using it on a
+//! live chain:
+//!
+//! ```text
+//! ./target/release/solochain-template-node benchmark pallet --wasm-execution=compiled \
+//!     --pallet pallet_kitties --extrinsic '*' --steps 20 --repeat 10 \
+//!     --output pallets/kitties/src/weights.rs --template .maintain/frame-weight-template.hbs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -35,11 +23,17 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_kitties`.
 pub trait WeightInfo {
-	fn create() -> Weight;
-	fn breed() -> Weight;
+	fn create(b: u32, ) -> Weight;
+	fn breed(b: u32, ) -> Weight;
 	fn transfer() -> Weight;
 	fn sale() -> Weight;
 	fn bid() -> Weight;
+	fn commit_seed() -> Weight;
+	fn reveal_seed(b: u32, ) -> Weight;
+	fn list_sire() -> Weight;
+	fn unlist_sire() -> Weight;
+	fn breed_with_sire(b: u32, ) -> Weight;
+	fn expire_seed() -> Weight;
 }
 
 /// Weights for `pallet_kitties` using the Substrate node and recommended hardware.
@@ -47,38 +41,56 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Kitties::NextKittyId` (r:1 w:1)
 	/// Proof: `Kitties::NextKittyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Kitties::Kitties` (r:0 w:1)
-	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `Kitties::KittyOwner` (r:0 w:1)
-	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	fn create() -> Weight {
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 63]`.
+	fn create(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `147`
-		//  Estimated: `1489`
-		// Minimum execution time: 43_000_000 picoseconds.
-		Weight::from_parts(45_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Measured:  `181 + b * (53 ±0)`
+		//  Estimated: `4879`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 4879)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `Kitties::Kitties` (r:2 w:1)
+	/// Storage: `Kitties::Kitties` (r:2 w:0)
 	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `Kitties::KittyOwner` (r:2 w:1)
+	/// Storage: `Kitties::KittyOwner` (r:2 w:0)
 	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittiesSaleInfo` (r:2 w:0)
+	/// Proof: `Kitties::KittiesSaleInfo` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyCooldowns` (r:2 w:2)
+	/// Proof: `Kitties::KittyCooldowns` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyChildren` (r:2 w:2)
+	/// Proof: `Kitties::KittyChildren` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `Kitties::NextKittyId` (r:1 w:1)
 	/// Proof: `Kitties::NextKittyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	fn breed() -> Weight {
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyGenerations` (r:2 w:0)
+	/// Proof: `Kitties::KittyGenerations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 63]`.
+	fn breed(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `404 + b * (53 ±0)`
 		//  Estimated: `6044`
-		// Minimum execution time: 65_000_000 picoseconds.
-		Weight::from_parts(80_000_000, 6044)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 6044)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Kitties::Kitties` (r:1 w:0)
 	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Kitties::KittyOwner` (r:1 w:1)
 	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::SireListings` (r:0 w:1)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `305`
@@ -86,7 +98,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 63_000_000 picoseconds.
 		Weight::from_parts(65_000_000, 3517)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Kitties::Kitties` (r:1 w:0)
 	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
@@ -124,44 +136,180 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Kitties::SeedCommitments` (r:1 w:1)
+	/// Proof: `Kitties::SeedCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn commit_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Kitties::SeedCommitments` (r:1 w:1)
+	/// Proof: `Kitties::SeedCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::RandomSeed` (r:1 w:1)
+	/// Proof: `Kitties::RandomSeed` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::Kitties` (r:128 w:64)
+	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyGenerations` (r:128 w:64)
+	/// Proof: `Kitties::KittyGenerations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyOwner` (r:0 w:64)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyParents` (r:0 w:64)
+	/// Proof: `Kitties::KittyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 64]`.
+	fn reveal_seed(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `260 + b * (90 ±0)`
+		//  Estimated: `4879 + b * (5022 ±0)`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 4879)
+			// Standard Error: 9_000_000
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 5022).saturating_mul(b.into()))
+	}
+	/// Storage: `Kitties::Kitties` (r:1 w:0)
+	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyOwner` (r:1 w:0)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittiesSaleInfo` (r:1 w:0)
+	/// Proof: `Kitties::KittiesSaleInfo` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::SireListings` (r:0 w:1)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn list_sire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `3517`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Kitties::KittyOwner` (r:1 w:0)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::SireListings` (r:1 w:1)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn unlist_sire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Kitties::SireListings` (r:1 w:0)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::Kitties` (r:1 w:0)
+	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyOwner` (r:2 w:0)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittiesSaleInfo` (r:2 w:0)
+	/// Proof: `Kitties::KittiesSaleInfo` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyCooldowns` (r:2 w:2)
+	/// Proof: `Kitties::KittyCooldowns` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyChildren` (r:2 w:2)
+	/// Proof: `Kitties::KittyChildren` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::NextKittyId` (r:1 w:1)
+	/// Proof: `Kitties::NextKittyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyGenerations` (r:2 w:0)
+	/// Proof: `Kitties::KittyGenerations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 63]`.
+	fn breed_with_sire(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + b * (53 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 92_000_000 picoseconds.
+		Weight::from_parts(104_000_000, 6196)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Kitties::SeedCommitments` (r:1 w:1)
+	/// Proof: `Kitties::SeedCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn expire_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3593`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Kitties::NextKittyId` (r:1 w:1)
 	/// Proof: `Kitties::NextKittyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Kitties::Kitties` (r:0 w:1)
-	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `Kitties::KittyOwner` (r:0 w:1)
-	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	fn create() -> Weight {
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 63]`.
+	fn create(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `147`
-		//  Estimated: `1489`
-		// Minimum execution time: 43_000_000 picoseconds.
-		Weight::from_parts(45_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Measured:  `181 + b * (53 ±0)`
+		//  Estimated: `4879`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 4879)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: `Kitties::Kitties` (r:2 w:1)
+	/// Storage: `Kitties::Kitties` (r:2 w:0)
 	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `Kitties::KittyOwner` (r:2 w:1)
+	/// Storage: `Kitties::KittyOwner` (r:2 w:0)
 	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittiesSaleInfo` (r:2 w:0)
+	/// Proof: `Kitties::KittiesSaleInfo` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyCooldowns` (r:2 w:2)
+	/// Proof: `Kitties::KittyCooldowns` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyChildren` (r:2 w:2)
+	/// Proof: `Kitties::KittyChildren` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `Kitties::NextKittyId` (r:1 w:1)
 	/// Proof: `Kitties::NextKittyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	fn breed() -> Weight {
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyGenerations` (r:2 w:0)
+	/// Proof: `Kitties::KittyGenerations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 63]`.
+	fn breed(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `404 + b * (53 ±0)`
 		//  Estimated: `6044`
-		// Minimum execution time: 65_000_000 picoseconds.
-		Weight::from_parts(80_000_000, 6044)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 6044)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Kitties::Kitties` (r:1 w:0)
 	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Kitties::KittyOwner` (r:1 w:1)
 	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::SireListings` (r:0 w:1)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `305`
@@ -169,7 +317,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 63_000_000 picoseconds.
 		Weight::from_parts(65_000_000, 3517)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Kitties::Kitties` (r:1 w:0)
 	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
@@ -207,4 +355,122 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `Kitties::SeedCommitments` (r:1 w:1)
+	/// Proof: `Kitties::SeedCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn commit_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Kitties::SeedCommitments` (r:1 w:1)
+	/// Proof: `Kitties::SeedCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::RandomSeed` (r:1 w:1)
+	/// Proof: `Kitties::RandomSeed` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::Kitties` (r:128 w:64)
+	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyGenerations` (r:128 w:64)
+	/// Proof: `Kitties::KittyGenerations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyOwner` (r:0 w:64)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyParents` (r:0 w:64)
+	/// Proof: `Kitties::KittyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 64]`.
+	fn reveal_seed(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `260 + b * (90 ±0)`
+		//  Estimated: `4879 + b * (5022 ±0)`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 4879)
+			// Standard Error: 9_000_000
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 5022).saturating_mul(b.into()))
+	}
+	/// Storage: `Kitties::Kitties` (r:1 w:0)
+	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyOwner` (r:1 w:0)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittiesSaleInfo` (r:1 w:0)
+	/// Proof: `Kitties::KittiesSaleInfo` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::SireListings` (r:0 w:1)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn list_sire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `3517`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Kitties::KittyOwner` (r:1 w:0)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::SireListings` (r:1 w:1)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn unlist_sire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Kitties::SireListings` (r:1 w:0)
+	/// Proof: `Kitties::SireListings` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::Kitties` (r:1 w:0)
+	/// Proof: `Kitties::Kitties` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyOwner` (r:2 w:0)
+	/// Proof: `Kitties::KittyOwner` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittiesSaleInfo` (r:2 w:0)
+	/// Proof: `Kitties::KittiesSaleInfo` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyCooldowns` (r:2 w:2)
+	/// Proof: `Kitties::KittyCooldowns` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyChildren` (r:2 w:2)
+	/// Proof: `Kitties::KittyChildren` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::NextKittyId` (r:1 w:1)
+	/// Proof: `Kitties::NextKittyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::PendingBirths` (r:1 w:1)
+	/// Proof: `Kitties::PendingBirths` (`max_values`: Some(1), `max_size`: Some(3394), added: 3889, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Kitties::KittyGenerations` (r:2 w:0)
+	/// Proof: `Kitties::KittyGenerations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 63]`.
+	fn breed_with_sire(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + b * (53 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 92_000_000 picoseconds.
+		Weight::from_parts(104_000_000, 6196)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Kitties::SeedCommitments` (r:1 w:1)
+	/// Proof: `Kitties::SeedCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn expire_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3593`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -107,7 +107,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 102,
+    spec_version: 104,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -259,17 +259,25 @@ impl pallet_template::Config for Runtime {
     type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const KittyMutationRate: Permill = Permill::from_percent(1);
+}
+
 impl pallet_kitties::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
-    type Randomness = Random;
+    type Randomness = pallet_kitties::RevealedRandomness<Runtime>;
     type BidMargin = ConstU128<100>;
     type KittyCost = ConstU128<500>;
+    type MutationRate = KittyMutationRate;
+    type BreedingCooldown = ConstU32<{ 10 * MINUTES }>;
+    type MaxChildrenPerKitty = ConstU32<16>;
+    type SeedDeposit = ConstU128<500>;
+    type RevealPeriod = ConstU32<{ 10 * MINUTES }>;
+    type MaxPendingBirths = ConstU32<64>;
     type Currency = Balances;
 }
 
-impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
-
 //offchain worker
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
 where
@@ -323,9 +331,6 @@ mod runtime {
 
     #[runtime::pallet_index(8)]
     pub type Kitties = pallet_kitties;
-
-    #[runtime::pallet_index(9)]
-    pub type Random = pallet_insecure_randomness_collective_flip;
 }
 
 /// The address format for describing accounts.
@@ -346,6 +351,12 @@ pub type SignedExtra = (
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 
+parameter_types! {
+    /// Name `pallet_insecure_randomness_collective_flip` was deployed under, whose storage is
+    /// cleared now that kitty DNA comes from `pallet_kitties::RevealedRandomness`.
+    pub const RandomPalletName: &'static str = "Random";
+}
+
 pub struct ExampleMigration<T: pallet_kitties::Config>(core::marker::PhantomData<T>);
 
 impl<T: pallet_kitties::Config> frame_support::traits::OnRuntimeUpgrade for ExampleMigration<T> {
@@ -375,7 +386,10 @@ impl<T: pallet_kitties::Config> frame_support::traits::OnRuntimeUpgrade for Exam
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = (ExampleMigration<Runtime>);
+type Migrations = (
+    ExampleMigration<Runtime>,
+    frame_support::migrations::RemovePallet<RandomPalletName, RocksDbWeight>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -529,6 +543,22 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_kitties_runtime_api::KittiesApi<Block> for Runtime {
+        fn kitty_details(kitty_id: u32) -> Option<pallet_kitties_runtime_api::KittyDetails> {
+            pallet_kitties::Kitties::<Runtime>::get(kitty_id).map(|kitty| {
+                pallet_kitties_runtime_api::KittyDetails {
+                    gene: kitty.gene,
+                    traits: pallet_kitties::KittyTraits::from_gene(&kitty.gene),
+                    generation: pallet_kitties::KittyGenerations::<Runtime>::get(kitty_id),
+                }
+            })
+        }
+
+        fn decode_gene(gene: [u8; 16]) -> pallet_kitties::KittyTraits {
+            pallet_kitties::KittyTraits::from_gene(&gene)
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,